@@ -0,0 +1,106 @@
+//--------------------------------------------------------------------------------
+// Benchmarks: Criterion timings of every day with an input
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Benchmarks parsing and both parts of every day whose input can be found, with criterion: `cargo bench -p aoc`.
+//! Inputs are read from the directory in `AOC_INPUTS` (named `dayNN.txt`), or else found the way `aoc run` finds them:
+//! `dayNN/input.txt`, then the cache. Nothing is downloaded, so days without an input on disk are skipped.
+//!
+//! The slowest days are also run on generated inputs of growing size, to show how they scale.
+
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use aoc::calendar::{self, Visitor};
+use aoc::config::Config;
+use aoc::inputs::InputManager;
+use aoc_common::Solver;
+use aoc_gen::Size;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+struct Register<'a> {
+    criterion: &'a mut Criterion,
+    input: &'a str
+}
+
+impl Visitor for Register<'_> {
+    type Output = ();
+
+    fn visit<S: Solver>(self) -> Self::Output {
+        let parsed = match S::parse(self.input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                eprintln!("Skipping day {}: {error}", S::DAY);
+                return;
+            }
+        };
+
+        let mut group = self.criterion.benchmark_group(format!("day{:02}", S::DAY));
+        group.bench_function("parse", |bencher| bencher.iter(|| S::parse(black_box(self.input))));
+        group.bench_function("part_one", |bencher| bencher.iter(|| S::part_one(black_box(&parsed))));
+        group.bench_function("part_two", |bencher| bencher.iter(|| S::part_two(black_box(&parsed))));
+        group.finish();
+    }
+}
+
+struct RegisterScaling<'a> {
+    criterion: &'a mut Criterion,
+    sizes: &'a [Size]
+}
+
+impl Visitor for RegisterScaling<'_> {
+    type Output = ();
+
+    fn visit<S: Solver>(self) -> Self::Output {
+        let mut group = self.criterion.benchmark_group(format!("day{:02}_scaling", S::DAY));
+        group.sample_size(10);
+        for size in self.sizes {
+            let input = aoc_gen::generate(S::DAY, 0, size).unwrap();
+            let parsed = S::parse(&input).unwrap();
+            let label = format!("{}x{}x{}", size.count, size.width, size.height);
+            group.bench_with_input(BenchmarkId::new("part_one", &label), &parsed, |bencher, parsed| bencher.iter(|| S::part_one(parsed)));
+            group.bench_with_input(BenchmarkId::new("part_two", &label), &parsed, |bencher, parsed| bencher.iter(|| S::part_two(parsed)));
+        }
+        group.finish();
+    }
+}
+
+fn input_path(input_manager: &InputManager, day: u8) -> Option<PathBuf> {
+    if let Some(inputs) = env::var_os("AOC_INPUTS") {
+        return Some(PathBuf::from(inputs).join(format!("day{day:02}.txt")));
+    }
+    return input_manager.find(day);
+}
+
+fn days(criterion: &mut Criterion) {
+    // Benchmarks run from the `aoc` directory, so the day crates and the config are one level up.
+    let workspace_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let config_path = env::var_os("AOC_CONFIG").map(PathBuf::from).unwrap_or_else(|| workspace_dir.join(".aoc.toml"));
+    let config = Config::load(&config_path).unwrap_or_else(|error| panic!("{error}"));
+    let input_manager = InputManager { workspace_dir: Some(workspace_dir), ..config.input_manager() };
+    for &day in calendar::DAYS {
+        let Some(Ok(input)) = input_path(&input_manager, day).map(fs::read_to_string) else {
+            continue;
+        };
+        calendar::visit(day, Register { criterion, input: &input });
+    }
+}
+
+fn scaling(criterion: &mut Criterion) {
+    let records = |width: usize| Size { count: 20, width, height: 1 };
+    let grid = |side: usize| Size { count: 1, width: side, height: side };
+    let days: [(u8, Vec<Size>); 3] = [
+        (12, vec![records(8), records(12), records(16)]),
+        (16, vec![grid(10), grid(20), grid(40)]),
+        (17, vec![grid(10), grid(20), grid(40)])
+    ];
+    for (day, sizes) in days {
+        calendar::visit(day, RegisterScaling { criterion, sizes: &sizes });
+    }
+}
+
+criterion_group!(benches, days, scaling);
+criterion_main!(benches);
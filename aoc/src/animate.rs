@@ -0,0 +1,185 @@
+//--------------------------------------------------------------------------------
+// Animate: Terminal playback of the days that are simulations
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Plays a day's simulation back in the terminal a frame at a time, for the days whose solvers go step by step:
+//!
+//! - Day 10: the breadth first search spreading out from the start both ways around the loop.
+//! - Day 14: the rounded rocks rolling a tile at a time through one spin cycle: north, west, south, then east.
+//! - Day 16: the beams of light moving through the contraption, energizing tiles as they go.
+//!
+//! Each day hands its frames over one by one as its iterator of intermediate states yields them, so nothing is kept
+//! but the frame on screen.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use aoc_common::ParseError;
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_render::Colour;
+
+use crate::render::{BEAM, ENERGIZED, FADED, PIPE, ROCK, STONE};
+
+/// Every day that can be animated.
+pub const DAYS: &[u8] = &[10, 14, 16];
+
+/// A tile of a frame: what to print and what colour to print it in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub symbol: char,
+    pub colour: Colour
+}
+
+/// One step of a simulation.
+pub type Frame = Grid<Tile>;
+
+/// Calls `show` with every frame of `day`'s simulation of `input`, in order, or returns `None` if the day cannot
+/// be animated.
+pub fn animate(day: u8, input: &str, show: impl FnMut(&Frame)) -> Option<Result<(), ParseError>> {
+    let animated = match day {
+        10 => day10(input, show),
+        14 => day14(input, show),
+        16 => day16(input, show),
+        _ => return None
+    };
+    return Some(animated);
+}
+
+/// The frame as lines of text, with each tile coloured by ANSI escape codes if `colour` is set.
+pub fn to_ansi(frame: &Frame, colour: bool) -> String {
+    let mut text = String::new();
+    for row in frame.rows() {
+        let mut current: Option<Colour> = None;
+        for tile in row {
+            if colour && current != Some(tile.colour) {
+                write!(text, "\x1b[38;2;{};{};{}m", tile.colour.r, tile.colour.g, tile.colour.b).unwrap();
+                current = Some(tile.colour);
+            }
+            text.push(tile.symbol);
+        }
+        if colour {
+            text.push_str("\x1b[0m");
+        }
+        text.push('\n');
+    }
+    return text;
+}
+
+fn day10(input: &str, mut show: impl FnMut(&Frame)) -> Result<(), ParseError> {
+    let map = day10::Map::parse(input)?;
+    let mut frame: Frame = map.grid.map(|&tile| {
+        // Box-drawing characters show the shape of the pipes far better than the letters of the sketch do.
+        let symbol = match char::from(tile) {
+            '|' => '│',
+            '-' => '─',
+            'L' => '└',
+            'J' => '┘',
+            '7' => '┐',
+            'F' => '┌',
+            symbol => symbol
+        };
+        return Tile { symbol, colour: FADED };
+    });
+
+    let mut previous: Vec<Pos> = Vec::new();
+    for frontier in map.bfs_frontiers() {
+        for position in previous {
+            frame[position].colour = PIPE;
+        }
+        for &position in &frontier {
+            frame[position].colour = BEAM;
+        }
+        show(&frame);
+        previous = frontier;
+    }
+    return Ok(());
+}
+
+fn day14(input: &str, mut show: impl FnMut(&Frame)) -> Result<(), ParseError> {
+    let map = day14::Map::parse(input)?;
+    let spin = [Direction::Up, Direction::Left, Direction::Down, Direction::Right];
+    for platform in map.tilt_frames(&spin) {
+        show(&platform.map(|&symbol| {
+            let colour = match symbol {
+                'O' => ROCK,
+                '#' => STONE,
+                _ => FADED
+            };
+            return Tile { symbol, colour };
+        }));
+    }
+    return Ok(());
+}
+
+fn day16(input: &str, mut show: impl FnMut(&Frame)) -> Result<(), ParseError> {
+    let cave = day16::Cave::parse(input)?;
+    let mut energized: HashSet<Pos> = HashSet::new();
+    for beams in cave.beam_fronts(None) {
+        energized.extend(beams.iter().map(|beam| beam.position));
+        let mut frame: Frame = Grid::from_fn(cave.grid.width(), cave.grid.height(), |position| {
+            match &cave.grid[position] {
+                day16::MapElement::Mirror { symbol } => return Tile { symbol: *symbol, colour: STONE },
+                day16::MapElement::Passthrough if energized.contains(&position) => return Tile { symbol: '#', colour: ENERGIZED },
+                day16::MapElement::Passthrough => return Tile { symbol: '.', colour: FADED }
+            }
+        });
+        for beam in &beams {
+            frame[beam.position] = Tile { symbol: beam.direction.arrow(), colour: BEAM };
+        }
+        show(&frame);
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_gen::Size;
+    use aoc_grid::Grid;
+    use aoc_render::Colour;
+
+    use crate::animate::{animate, to_ansi, Tile, DAYS};
+    use crate::render::BEAM;
+
+    #[test]
+    fn test_animate_generated_inputs() {
+        let size = Size { count: 8, width: 12, height: 9 };
+        for &day in DAYS {
+            let input = aoc_gen::generate(day, 3, &size).unwrap();
+            let mut frames = 0;
+            animate(day, &input, |frame| {
+                assert_eq!((frame.width(), frame.height()), (12, 9), "day {day}");
+                frames += 1;
+            }).unwrap().unwrap();
+            assert!(frames > 1, "day {day}");
+        }
+        assert!(animate(1, "1abc2\n", |_| {}).is_none());
+        assert!(animate(14, "O.\nx#\n", |_| {}).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_animate_day10() {
+        let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....\n";
+        let mut frontiers: Vec<usize> = Vec::new();
+        animate(10, input, |frame| frontiers.push(frame.iter().filter(|(_, tile)| tile.colour == BEAM).count())).unwrap().unwrap();
+        assert_eq!(frontiers, vec![1, 2, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_animate_day16() {
+        let mut frames: Vec<String> = Vec::new();
+        animate(16, ".\\.\n...\n", |frame| frames.push(to_ansi(frame, false))).unwrap().unwrap();
+        assert_eq!(frames, vec![">\\.\n...\n", "#>.\n...\n", "#\\.\n.v.\n"]);
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        let red = Colour::from_hex(0xff0000);
+        let frame: Grid<Tile> = Grid::from_rows(vec![
+            vec![Tile { symbol: 'a', colour: red }, Tile { symbol: 'b', colour: red }],
+            vec![Tile { symbol: 'c', colour: red }, Tile { symbol: 'd', colour: Colour::WHITE }]
+        ]).unwrap();
+        assert_eq!(to_ansi(&frame, false), "ab\ncd\n");
+        assert_eq!(to_ansi(&frame, true), "\x1b[38;2;255;0;0mab\x1b[0m\n\x1b[38;2;255;0;0mc\x1b[38;2;255;255;255md\x1b[0m\n");
+    }
+}
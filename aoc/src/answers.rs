@@ -0,0 +1,199 @@
+//--------------------------------------------------------------------------------
+// Answers: The answers the site has accepted, to check every run against
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! The examples only cover a sliver of each puzzle, so a refactor can pass every test and still break the real answer.
+//! Once the site accepts an answer, it is recorded in `answers.toml` under the day and a hash of the input it was for:
+//!
+//! ```toml
+//! [day01.3f9ab2e6c1d07a44]
+//! part1 = "54390"
+//! part2 = "54277"
+//! ```
+//!
+//! Every later run on the same input is checked against it.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::runner::DayReport;
+
+/// `AOC_ANSWERS`, or else `answers.toml` in the current directory.
+pub fn default_answers_path() -> PathBuf {
+    return env::var_os("AOC_ANSWERS").map(PathBuf::from).unwrap_or_else(|| PathBuf::from("answers.toml"));
+}
+
+/// The first 16 hex digits of the SHA-256 of `input`, which are plenty to tell one account's input from another's.
+pub fn input_hash(input: &str) -> String {
+    return Sha256::digest(input.as_bytes())[..8].iter().map(|byte| format!("{byte:02x}")).collect();
+}
+
+/// The accepted answers for one input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InputAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>
+}
+
+impl InputAnswers {
+    fn part(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => return &mut self.part1,
+            Part::Two => return &mut self.part2
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, error: io::Error },
+    /// The file is not a valid answers file.
+    Parse { path: PathBuf, message: String }
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            AnswersError::Parse { path, message } => write!(f, "{}: {}", path.display(), message.trim_end())
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// An answer that differs from the one the site accepted for the same input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {:02}, part {}: expected {}, got {}", self.day, self.part, self.expected, self.actual)
+    }
+}
+
+/// Every accepted answer, by day (`dayNN`) and then by input hash.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, InputAnswers>>
+}
+
+impl Answers {
+    /// The answers in `path`, or none if there is no such file.
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(error) => return Err(AnswersError::Io { path: path.to_path_buf(), error })
+        };
+        return toml::from_str(&contents).map_err(|error| AnswersError::Parse { path: path.to_path_buf(), message: error.to_string() });
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let contents = toml::to_string(self).unwrap();
+        return fs::write(path, contents).map_err(|error| AnswersError::Io { path: path.to_path_buf(), error });
+    }
+
+    pub fn get(&self, day: u8, input_hash: &str, part: Part) -> Option<&str> {
+        let answers = self.days.get(&format!("day{day:02}"))?.get(input_hash)?;
+        match part {
+            Part::One => return answers.part1.as_deref(),
+            Part::Two => return answers.part2.as_deref()
+        }
+    }
+
+    /// Records `answer` as the accepted answer to the part for the input, replacing any answer recorded before.
+    pub fn record(&mut self, day: u8, input_hash: &str, part: Part, answer: &str) {
+        let answers = self.days.entry(format!("day{day:02}")).or_default().entry(input_hash.to_string()).or_default();
+        *answers.part(part) = Some(answer.to_string());
+    }
+
+    /// Every answer in `report` that differs from the recorded one for the input. Parts with nothing recorded pass.
+    pub fn check(&self, input_hash: &str, report: &DayReport) -> Vec<Mismatch> {
+        return report.parts.iter()
+            .filter_map(|part| {
+                // A part that ran out of time has no answer to check.
+                let actual = part.answer.as_ref()?;
+                let expected = self.get(report.day, input_hash, part.part)?;
+                if expected == actual {
+                    return None;
+                }
+                return Some(Mismatch { day: report.day, part: part.part, expected: expected.to_string(), actual: actual.clone() });
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use aoc_common::{Params, Part};
+
+    use crate::answers::{input_hash, Answers, Mismatch};
+    use crate::calendar;
+    use crate::runner::Run;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "e3b0c44298fc1c14");
+        assert_ne!(input_hash("1abc2\n"), input_hash("1abc2"));
+    }
+
+    #[test]
+    fn test_record_and_save() {
+        let mut answers = Answers::default();
+        answers.record(1, "00ff", Part::Two, "281");
+        answers.record(1, "00ff", Part::One, "142");
+        answers.record(1, "00ff", Part::One, "143");
+        assert_eq!(answers.get(1, "00ff", Part::One), Some("143"));
+        assert_eq!(answers.get(1, "ff00", Part::One), None);
+        assert_eq!(answers.get(2, "00ff", Part::One), None);
+
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        answers.save(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "[day01.00ff]\npart1 = \"143\"\npart2 = \"281\"\n");
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+
+        fs::write(&path, "[day01.00ff]\npart3 = 1\n").unwrap();
+        assert!(Answers::load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+    }
+
+    #[test]
+    fn test_check() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        let hash = input_hash(input);
+        let report = calendar::visit(1, Run { input, parts: &Part::BOTH, params: &Params::new(), budget: None }).unwrap().unwrap();
+
+        let mut answers = Answers::default();
+        assert!(answers.check(&hash, &report).is_empty());
+
+        answers.record(1, &hash, Part::One, "142");
+        answers.record(1, &hash, Part::Two, "281");
+        let mismatches = answers.check(&hash, &report);
+        assert_eq!(mismatches, vec![Mismatch { day: 1, part: Part::Two, expected: "281".to_string(), actual: "142".to_string() }]);
+        assert_eq!(mismatches[0].to_string(), "Day 01, part 2: expected 281, got 142");
+
+        assert!(answers.check(&input_hash("1abc2\n"), &report).is_empty());
+    }
+}
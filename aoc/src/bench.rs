@@ -0,0 +1,143 @@
+//--------------------------------------------------------------------------------
+// Bench: Times each step of a day over many runs
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_common::{ParseError, Solver};
+use serde::Serialize;
+
+use crate::calendar::Visitor;
+
+/// How long a step took over every sample, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Timing {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64
+}
+
+impl Timing {
+    /// Runs `step` `samples` times (at least once) and summarises how long it took.
+    pub fn measure<T>(samples: usize, mut step: impl FnMut() -> T) -> Timing {
+        let mut durations: Vec<u64> = (0..samples.max(1))
+            .map(|_| {
+                let start = Instant::now();
+                black_box(step());
+                return start.elapsed().as_nanos() as u64;
+            })
+            .collect();
+        durations.sort();
+
+        return Timing {
+            min_ns: durations[0],
+            median_ns: durations[durations.len() / 2],
+            mean_ns: durations.iter().sum::<u64>() / durations.len() as u64
+        };
+    }
+
+    pub fn median(&self) -> Duration {
+        return Duration::from_nanos(self.median_ns);
+    }
+}
+
+/// The timings of one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct DayBenchmark {
+    pub day: u8,
+    pub title: &'static str,
+    pub parse: Timing,
+    pub part_one: Timing,
+    pub part_two: Timing
+}
+
+/// The timings of every day benchmarked, as written to the JSON and markdown reports.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    /// How many times each step was run.
+    pub samples: usize,
+    pub days: Vec<DayBenchmark>
+}
+
+impl BenchReport {
+    pub fn to_json(&self) -> String {
+        return serde_json::to_string_pretty(self).unwrap();
+    }
+
+    /// A table of the median timings, one row per day.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        writeln!(markdown, "| Day | Title | Parse | Part 1 | Part 2 | Total |").unwrap();
+        writeln!(markdown, "|----:|:------|------:|-------:|-------:|------:|").unwrap();
+        for day in &self.days {
+            let total = day.parse.median() + day.part_one.median() + day.part_two.median();
+            writeln!(markdown, "| {:02} | {} | {:.2?} | {:.2?} | {:.2?} | {:.2?} |",
+                day.day, day.title, day.parse.median(), day.part_one.median(), day.part_two.median(), total).unwrap();
+        }
+        writeln!(markdown, "\nMedian of {} samples per step.", self.samples).unwrap();
+        return markdown;
+    }
+}
+
+/// Times parsing `input` and solving both parts with it, `samples` times each.
+pub fn bench<S: Solver>(input: &str, samples: usize) -> Result<DayBenchmark, ParseError> {
+    let parsed = S::parse(input)?;
+
+    return Ok(DayBenchmark {
+        day: S::DAY,
+        title: S::TITLE,
+        parse: Timing::measure(samples, || S::parse(input)),
+        part_one: Timing::measure(samples, || S::part_one(&parsed)),
+        part_two: Timing::measure(samples, || S::part_two(&parsed))
+    });
+}
+
+/// Benchmarks whichever solver it is handed on the given input.
+pub struct Bench<'a> {
+    pub input: &'a str,
+    pub samples: usize
+}
+
+impl Visitor for Bench<'_> {
+    type Output = Result<DayBenchmark, ParseError>;
+
+    fn visit<S: Solver>(self) -> Self::Output {
+        return bench::<S>(self.input, self.samples);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bench::{Bench, BenchReport, Timing};
+    use crate::calendar;
+
+    #[test]
+    fn test_measure() {
+        let mut runs = 0;
+        let timing = Timing::measure(5, || runs += 1);
+        assert_eq!(runs, 5);
+        assert!(timing.min_ns <= timing.median_ns);
+
+        Timing::measure(0, || runs += 1);
+        assert_eq!(runs, 6);
+    }
+
+    #[test]
+    fn test_report() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        let day = calendar::visit(1, Bench { input, samples: 3 }).unwrap().unwrap();
+        let report = BenchReport { samples: 3, days: vec![day] };
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["samples"], 3);
+        assert_eq!(json["days"][0]["day"], 1);
+        assert_eq!(json["days"][0]["title"], "Trebuchet?!");
+        assert!(json["days"][0]["part_two"]["median_ns"].is_u64());
+
+        let markdown = report.to_markdown();
+        assert!(markdown.lines().nth(2).unwrap().starts_with("| 01 | Trebuchet?! |"));
+    }
+}
@@ -0,0 +1,82 @@
+//--------------------------------------------------------------------------------
+// Calendar: Every day the runner knows how to solve
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use aoc_common::Solver;
+
+/// Something to do with a day's solver. Since `Solver` has associated types, the
+/// runner cannot keep a list of them, so it hands the solver type to a visitor instead.
+pub trait Visitor {
+    type Output;
+
+    fn visit<S: Solver>(self) -> Self::Output;
+}
+
+macro_rules! calendar {
+    ($($solver:ty),* $(,)?) => {
+        /// Every day that has a solver, in calendar order.
+        pub const DAYS: &[u8] = &[$(<$solver as Solver>::DAY),*];
+
+        /// Calls the visitor with the solver registered for `day`, if there is one.
+        pub fn visit<V: Visitor>(day: u8, visitor: V) -> Option<V::Output> {
+            $(
+                if day == <$solver as Solver>::DAY {
+                    return Some(visitor.visit::<$solver>());
+                }
+            )*
+            return None;
+        }
+    };
+}
+
+calendar![
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day16::Day16,
+    day17::Day17,
+    day18::Day18,
+    day19::Day19,
+];
+
+#[cfg(test)]
+mod test {
+    use crate::calendar::{visit, Visitor, DAYS};
+    use aoc_common::Solver;
+
+    struct Title;
+
+    impl Visitor for Title {
+        type Output = &'static str;
+
+        fn visit<S: Solver>(self) -> Self::Output {
+            return S::TITLE;
+        }
+    }
+
+    #[test]
+    fn test_days_are_in_order() {
+        assert_eq!(DAYS[..19], (1..=19).collect::<Vec<u8>>());
+        assert!(DAYS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(DAYS.iter().all(|day| (1..=25).contains(day)));
+    }
+
+    #[test]
+    fn test_visit() {
+        assert_eq!(visit(17, Title), Some("Clumsy Crucible"));
+        assert_eq!(visit(25, Title), None);
+    }
+}
@@ -0,0 +1,277 @@
+//--------------------------------------------------------------------------------
+// Config: The project's settings in `.aoc.toml`
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Settings that would otherwise be hard-coded or set through the environment on every run, read from `.aoc.toml`:
+//!
+//! ```toml
+//! year = 2023                       # which event to download inputs from and send answers to
+//! input_dir = "inputs"              # where the inputs are kept as dayNN.txt, relative to this file
+//! session = { env = "AOC_TOKEN" }   # or { file = "/home/me/.aoc-session" }, to read the token's first line
+//!
+//! # The constants a puzzle gives in its text, overridden for `aoc run`.
+//! [params.day02.bag]
+//! red = 12
+//! green = 13
+//! blue = 14
+//!
+//! [params.day11]
+//! expansion = 1_000_000
+//! ```
+//!
+//! Every setting is optional. Without `input_dir`, inputs go in the cache directory, and `AOC_CACHE_DIR` still takes
+//! precedence over either. Inputs may not be shared, so an `input_dir` inside the repository has to be one git ignores,
+//! like `inputs`. Without `session`, the token is looked for in `AOC_SESSION` and then `~/.config/aoc/session`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Params, Solver};
+use serde::Deserialize;
+
+use crate::calendar::{self, Visitor};
+use crate::inputs::{cache_dir_for, default_session, InputManager, DEFAULT_YEAR};
+use crate::submit::Submitter;
+
+/// `AOC_CONFIG`, or else `.aoc.toml` in the current directory.
+pub fn default_config_path() -> PathBuf {
+    return env::var_os("AOC_CONFIG").map(PathBuf::from).unwrap_or_else(|| PathBuf::from(".aoc.toml"));
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io { path: PathBuf, error: io::Error },
+    /// The file is not a valid config file.
+    Parse { path: PathBuf, message: String }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            ConfigError::Parse { path, message } => write!(f, "{}: {}", path.display(), message.trim_end())
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Where to read the session token from.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase", deny_unknown_fields)]
+pub enum SessionSource {
+    /// The environment variable with this name.
+    Env(String),
+    /// The first line of this file.
+    File(PathBuf)
+}
+
+impl SessionSource {
+    /// The token, if the variable or file has one.
+    pub fn read(&self) -> Option<String> {
+        let session = match self {
+            SessionSource::Env(variable) => env::var(variable).ok()?,
+            SessionSource::File(path) => fs::read_to_string(path).ok()?.lines().next()?.to_string()
+        };
+        return Some(session.trim().to_string()).filter(|session| !session.is_empty());
+    }
+}
+
+/// The file as written, before its params are checked against the solvers.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    year: Option<u16>,
+    input_dir: Option<PathBuf>,
+    session: Option<SessionSource>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Table>
+}
+
+/// The settings of the project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub year: u16,
+    /// Where the inputs are kept, if not in the cache directory.
+    pub input_dir: Option<PathBuf>,
+    /// Where to read the session token from, if not the usual places.
+    pub session: Option<SessionSource>,
+    /// The params to solve each day with.
+    pub params: BTreeMap<u8, Params>
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        return Config { year: DEFAULT_YEAR, input_dir: None, session: None, params: BTreeMap::new() };
+    }
+}
+
+/// The names of the params a day's solver reads.
+struct ParamNames;
+
+impl Visitor for ParamNames {
+    type Output = &'static [&'static str];
+
+    fn visit<S: Solver>(self) -> Self::Output {
+        return S::PARAMS;
+    }
+}
+
+impl Config {
+    /// The settings in `path`, or the defaults if there is no such file. Every param must be one its day's solver reads.
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(error) => return Err(ConfigError::Io { path: path.to_path_buf(), error })
+        };
+        let parse_error = |message: String| ConfigError::Parse { path: path.to_path_buf(), message };
+        let file: ConfigFile = toml::from_str(&contents).map_err(|error| parse_error(error.to_string()))?;
+
+        let mut params: BTreeMap<u8, Params> = BTreeMap::new();
+        for (day_name, table) in &file.params {
+            let Some(day) = day_name.strip_prefix("day").and_then(|day| day.parse::<u8>().ok()) else {
+                return Err(parse_error(format!("expected params for a day like `day02`, found `{day_name}`")));
+            };
+            let Some(names) = calendar::visit(day, ParamNames) else {
+                return Err(parse_error(format!("there is no solver for {day_name}")));
+            };
+
+            let mut day_params = Params::new();
+            for (name, value) in flatten(table) {
+                if !names.contains(&name.as_str()) {
+                    let known = if names.is_empty() { "none".to_string() } else { names.join(", ") };
+                    return Err(parse_error(format!("{day_name} has no param `{name}` (it has {known})")));
+                }
+                let Some(value) = value.as_integer().and_then(|value| u64::try_from(value).ok()) else {
+                    return Err(parse_error(format!("expected a whole number of at least 0 for `{name}` of {day_name}, found `{value}`")));
+                };
+                day_params.set(&name, value);
+            }
+            params.insert(day, day_params);
+        }
+
+        // A relative input directory is relative to the file, so the same file works from anywhere.
+        let input_dir = file.input_dir.map(|input_dir| path.parent().unwrap_or(Path::new("")).join(input_dir));
+        return Ok(Config { year: file.year.unwrap_or(DEFAULT_YEAR), input_dir, session: file.session, params });
+    }
+
+    /// The params to solve `day` with, which are none unless the file sets some.
+    pub fn params(&self, day: u8) -> &Params {
+        static NO_PARAMS: Params = Params::new();
+        return self.params.get(&day).unwrap_or(&NO_PARAMS);
+    }
+
+    pub fn base_url(&self) -> String {
+        return format!("https://adventofcode.com/{}", self.year);
+    }
+
+    /// `AOC_CACHE_DIR` if it is set, or else the input directory, or else the cache directory for the year.
+    pub fn cache_dir(&self) -> PathBuf {
+        match &self.input_dir {
+            Some(input_dir) if env::var_os("AOC_CACHE_DIR").is_none() => return input_dir.clone(),
+            _ => return cache_dir_for(self.year)
+        }
+    }
+
+    pub fn session_token(&self) -> Option<String> {
+        match &self.session {
+            Some(source) => return source.read(),
+            None => return default_session()
+        }
+    }
+
+    /// The input manager for the year, keeping inputs in [`Config::cache_dir`].
+    pub fn input_manager(&self) -> InputManager {
+        return InputManager {
+            base_url: self.base_url(),
+            cache_dir: self.cache_dir(),
+            session: self.session_token(),
+            workspace_dir: Some(PathBuf::from("."))
+        };
+    }
+
+    /// The submitter for the year, keeping its history in the cache directory for the year even if the inputs are
+    /// kept in the input directory, since the history is no more for sharing than they are.
+    pub fn submitter(&self) -> Submitter {
+        return Submitter {
+            base_url: self.base_url(),
+            session: self.session_token(),
+            history_path: cache_dir_for(self.year).join("submissions.json")
+        };
+    }
+}
+
+/// Every number in `table` by its dotted name, so that `[params.day02.bag]` with `red = 12` gives `bag.red`.
+fn flatten(table: &toml::Table) -> Vec<(String, toml::Value)> {
+    let mut flattened: Vec<(String, toml::Value)> = Vec::new();
+    for (key, value) in table {
+        match value {
+            toml::Value::Table(inner) => {
+                flattened.extend(flatten(inner).into_iter().map(|(name, value)| (format!("{key}.{name}"), value)));
+            },
+            _ => flattened.push((key.clone(), value.clone()))
+        }
+    }
+    return flattened;
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::config::{Config, SessionSource};
+
+    fn load(name: &str, contents: &str) -> Result<Config, String> {
+        let path = std::env::temp_dir().join(format!("aoc-config-{name}-{}.toml", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        let config = Config::load(&path).map_err(|error| error.to_string());
+        fs::remove_file(&path).unwrap();
+        return config;
+    }
+
+    #[test]
+    fn test_load() {
+        let config = load("full", "\
+year = 2022
+input_dir = \"inputs\"
+session = { file = \"/nowhere/session\" }
+
+[params.day02.bag]
+red = 20
+blue = 1
+
+[params.day11]
+expansion = 1_000
+").unwrap();
+        assert_eq!(config.year, 2022);
+        assert_eq!(config.base_url(), "https://adventofcode.com/2022");
+        assert_eq!(config.input_dir, Some(std::env::temp_dir().join("inputs")));
+        assert_eq!(config.session, Some(SessionSource::File(PathBuf::from("/nowhere/session"))));
+        assert_eq!(config.session_token(), None);
+        assert_eq!(config.params(2).names().collect::<Vec<&str>>(), vec!["bag.blue", "bag.red"]);
+        assert_eq!(config.params(2).get_or("bag.red", 12), 20);
+        assert_eq!(config.params(11).get_or("expansion", 1_000_000), 1_000);
+        assert_eq!(config.params(5).names().count(), 0);
+
+        assert_eq!(load("empty", "").unwrap(), Config::default());
+        assert_eq!(Config::load(&std::env::temp_dir().join("aoc-config-missing.toml")).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_load_errors() {
+        assert!(load("unknown", "yaer = 2023\n").unwrap_err().contains("unknown field `yaer`"));
+        assert!(load("day", "[params.december]\nx = 1\n").unwrap_err().contains("expected params for a day like `day02`"));
+        assert!(load("solver", "[params.day24]\nx = 1\n").unwrap_err().contains("there is no solver for day24"));
+        assert!(load("param", "[params.day02.bag]\npurple = 1\n").unwrap_err().contains("day02 has no param `bag.purple` (it has bag.red, bag.green, bag.blue)"));
+        assert!(load("none", "[params.day01]\nx = 1\n").unwrap_err().contains("day01 has no param `x` (it has none)"));
+        assert!(load("negative", "[params.day11]\nexpansion = -2\n").unwrap_err().contains("expected a whole number"));
+        assert!(load("session", "session = { cookie = \"abc\" }\n").is_err());
+    }
+}
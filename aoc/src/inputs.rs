@@ -0,0 +1,210 @@
+//--------------------------------------------------------------------------------
+// Inputs: Finds each day's puzzle input, downloading it once if needed
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Puzzle inputs differ from account to account and may not be shared, so none are kept in this repository. The input
+//! manager downloads each one with the account's session cookie and keeps it in a cache directory outside the repository.
+
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The event the inputs are for, unless `.aoc.toml` says otherwise.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Sent with every request, as the Advent of Code site asks automated tools to identify themselves.
+pub(crate) const USER_AGENT: &str = "github.com/rjcculaway/advent-of-code-2023";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no input on disk and no session token to download it with.
+    NoSession { day: u8 },
+    /// The site answered with something other than the input.
+    Http { day: u8, status: u16, message: String },
+    /// The site could not be reached.
+    Transport { day: u8, message: String },
+    /// The cache could not be read or written.
+    Io { path: PathBuf, error: io::Error }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NoSession { day } => write!(f, "no input for day {day}, and no session token to download it with (set AOC_SESSION)"),
+            InputError::Http { day, status: 404, .. } => write!(f, "the input for day {day} is not available yet"),
+            InputError::Http { day, status: 400 | 401 | 403, .. } => write!(f, "the session token was not accepted when downloading day {day}; it may have expired"),
+            InputError::Http { day, status, message } => write!(f, "could not download day {day}: {status} {message}"),
+            InputError::Transport { day, message } => write!(f, "could not download day {day}: {message}"),
+            InputError::Io { path, error } => write!(f, "{}: {error}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The user's cache directory for the inputs of `year`: `AOC_CACHE_DIR`, or `aoc-<year>` under `XDG_CACHE_HOME` or
+/// `~/.cache`.
+pub fn cache_dir_for(year: u16) -> PathBuf {
+    if let Some(cache_dir) = env::var_os("AOC_CACHE_DIR") {
+        return PathBuf::from(cache_dir);
+    }
+    let cache_home = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(env::temp_dir);
+    return cache_home.join(format!("aoc-{year}"));
+}
+
+/// The session token in `AOC_SESSION`, or else the first line of `aoc/session` under `XDG_CONFIG_HOME` or `~/.config`.
+pub fn default_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session.trim().to_string()).filter(|session| !session.is_empty());
+    }
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    let session = fs::read_to_string(config_home.join("aoc").join("session")).ok()?;
+    return session.lines().next().map(|line| line.trim().to_string()).filter(|session| !session.is_empty());
+}
+
+/// Finds, downloads and caches puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct InputManager {
+    /// The site to download from, without a trailing slash.
+    pub base_url: String,
+    /// Where downloaded inputs are kept, as `dayNN.txt`.
+    pub cache_dir: PathBuf,
+    /// The value of the `session` cookie of a logged in account.
+    pub session: Option<String>,
+    /// The directory of the day crates, checked for a `dayNN/input.txt` put there by hand before anything else.
+    pub workspace_dir: Option<PathBuf>
+}
+
+impl InputManager {
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        return self.cache_dir.join(format!("day{day:02}.txt"));
+    }
+
+    /// Where the input of `day` is on disk, without downloading it.
+    pub fn find(&self, day: u8) -> Option<PathBuf> {
+        let by_hand = self.workspace_dir.as_ref().map(|workspace_dir| workspace_dir.join(format!("day{day:02}")).join("input.txt"));
+        return [by_hand, Some(self.cached_path(day))].into_iter().flatten().find(|path| path.is_file());
+    }
+
+    /// Where the input of `day` is on disk, downloading it into the cache first if it is not there yet.
+    pub fn resolve(&self, day: u8) -> Result<PathBuf, InputError> {
+        if let Some(path) = self.find(day) {
+            return Ok(path);
+        }
+        self.fetch(day)?;
+        return Ok(self.cached_path(day));
+    }
+
+    /// Downloads the input of `day` into the cache, replacing any copy already there, and returns it.
+    pub fn fetch(&self, day: u8) -> Result<String, InputError> {
+        let Some(session) = &self.session else {
+            return Err(InputError::NoSession { day });
+        };
+
+        let url = format!("{}/day/{day}/input", self.base_url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        let input = match response {
+            Ok(response) => response.into_string().map_err(|error| InputError::Transport { day, message: error.to_string() })?,
+            Err(ureq::Error::Status(status, response)) => {
+                let message = response.into_string().unwrap_or_default().trim().to_string();
+                return Err(InputError::Http { day, status, message });
+            },
+            Err(error) => return Err(InputError::Transport { day, message: error.to_string() })
+        };
+
+        // Write to a temporary file first, so an interrupted download never leaves half an input in the cache.
+        let path = self.cached_path(day);
+        let partial_path = path.with_extension("part");
+        let io_error = |error: io::Error| InputError::Io { path: path.clone(), error };
+        fs::create_dir_all(&self.cache_dir).map_err(io_error)?;
+        fs::write(&partial_path, &input).map_err(io_error)?;
+        fs::rename(&partial_path, &path).map_err(io_error)?;
+        return Ok(input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::inputs::{InputError, InputManager};
+    use crate::stand_in::StandIn;
+
+    fn temporary_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        return dir;
+    }
+
+    fn manager(base_url: &str, cache_dir: PathBuf, session: Option<&str>) -> InputManager {
+        return InputManager { base_url: base_url.to_string(), cache_dir, session: session.map(str::to_string), workspace_dir: None };
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let server = StandIn::start(|request| {
+            match request.path.as_str() {
+                "/2023/day/1/input" => (200, "1abc2\n".to_string()),
+                _ => (404, "Not Found".to_string())
+            }
+        });
+        let cache_dir = temporary_dir("fetch");
+        let inputs = manager(&format!("{}/2023", server.url), cache_dir.clone(), Some("abc123"));
+
+        let path = inputs.resolve(1).unwrap();
+        assert_eq!(path, cache_dir.join("day01.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\n");
+
+        // The second time, the cached copy is used without asking the server again.
+        assert_eq!(inputs.resolve(1).unwrap(), path);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert!(requests[0].header("user-agent").is_some());
+
+        assert!(matches!(inputs.resolve(25), Err(InputError::Http { day: 25, status: 404, .. })));
+        assert!(!cache_dir.join("day25.txt").exists());
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_errors() {
+        let server = StandIn::start(|_| (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string()));
+        let cache_dir = temporary_dir("errors");
+
+        let inputs = manager(&server.url, cache_dir.clone(), Some("expired"));
+        let error = inputs.resolve(3).unwrap_err();
+        assert!(error.to_string().contains("session token was not accepted"));
+
+        let inputs = manager(&server.url, cache_dir.clone(), None);
+        assert!(matches!(inputs.resolve(3), Err(InputError::NoSession { day: 3 })));
+        assert_eq!(server.requests().len(), 1);
+        assert!(!cache_dir.exists());
+    }
+
+    #[test]
+    fn test_input_by_hand() {
+        let workspace_dir = temporary_dir("workspace");
+        fs::create_dir_all(workspace_dir.join("day07")).unwrap();
+        fs::write(workspace_dir.join("day07").join("input.txt"), "32T3K 765\n").unwrap();
+
+        let mut inputs = manager("http://127.0.0.1:9", workspace_dir.join("cache"), None);
+        inputs.workspace_dir = Some(workspace_dir.clone());
+        assert_eq!(inputs.resolve(7).unwrap(), workspace_dir.join("day07").join("input.txt"));
+        assert_eq!(inputs.find(8), None);
+        fs::remove_dir_all(workspace_dir).unwrap();
+    }
+}
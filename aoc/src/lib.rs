@@ -0,0 +1,20 @@
+//--------------------------------------------------------------------------------
+// Advent of Code 2023 runner: The pieces behind the `aoc` binary
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! The calendar of solvers and the ways the runner can put them to work, shared by the `aoc` binary and the benchmarks.
+
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod config;
+pub mod inputs;
+pub mod render;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+
+#[cfg(test)]
+mod stand_in;
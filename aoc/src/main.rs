@@ -0,0 +1,637 @@
+//--------------------------------------------------------------------------------
+// Advent of Code 2023 runner
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_common::{Params, Part, NO_ANSWER};
+use aoc_gen::Size;
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use tracing_subscriber::EnvFilter;
+
+use aoc::animate;
+use aoc::answers::{self, Answers};
+use aoc::bench::{Bench, BenchReport};
+use aoc::calendar;
+use aoc::config::{self, Config};
+use aoc::render;
+use aoc::runner::{self, Run};
+use aoc::scaffold::Scaffold;
+use aoc::submit::Verdict;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    /// Log what the solvers are doing to stderr: -v for each step, -vv for everything
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a day, or every day if none is given
+    Run {
+        /// The day to solve
+        day: Option<u8>,
+        /// Only solve this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// The puzzle input to use instead of the downloaded one, or - to read it from stdin
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+        /// How to print the answers
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Give up on a part after this many seconds, report it as timed out and carry on with the next
+        #[arg(short, long, value_parser = parse_timeout)]
+        timeout: Option<Duration>
+    },
+    /// Times parsing and both parts of a day, or of every day with an input
+    Bench {
+        /// The day to benchmark
+        day: Option<u8>,
+        /// A directory of inputs named dayNN.txt to use instead of the downloaded ones
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// How many times to run each step
+        #[arg(short, long, default_value_t = 10, value_parser = parse_samples)]
+        samples: usize,
+        /// Also write the report as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Also write the report as a markdown table to this file
+        #[arg(long)]
+        markdown: Option<PathBuf>
+    },
+    /// Downloads the input of a day, or of every day, into the cache
+    Fetch {
+        /// The day to download
+        day: Option<u8>,
+        /// Download the input again even if it is already cached
+        #[arg(short, long)]
+        force: bool
+    },
+    /// Sends the answer to a part of a day, solving it first if no answer is given
+    Submit {
+        /// The day to answer
+        day: u8,
+        /// The part to answer
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The answer to send instead of the one the solver gives
+        answer: Option<String>
+    },
+    /// Prints a random input for a day, the same one for the same seed and size
+    Gen {
+        /// The day to generate an input for
+        day: u8,
+        /// The seed of the random number generator
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// How many things the input lists, such as lines, hands, records or workflows
+        #[arg(short, long, default_value_t = Size::default().count)]
+        count: usize,
+        /// The width of the map, or the length of each line
+        #[arg(short, long, default_value_t = Size::default().width)]
+        width: usize,
+        /// The height of the map, or the second size the day's input has
+        #[arg(long, default_value_t = Size::default().height)]
+        height: usize,
+        /// Write the input to this file instead of printing it
+        #[arg(short, long)]
+        output: Option<PathBuf>
+    },
+    /// Draws what a day's solver found in its input, as an SVG or a PNG
+    Render {
+        /// The day to draw: 10, 14, 16, 17 or 18
+        day: u8,
+        /// The puzzle input to use instead of the downloaded one, or - to read it from stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// The file to write, as a PNG if it ends in .png and as an SVG otherwise [default: dayNN.svg]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// How many pixels wide each tile is
+        #[arg(long, default_value_t = 10)]
+        cell_size: u32
+    },
+    /// Plays a day's simulation back in the terminal, a step at a time
+    Animate {
+        /// The day to play: 10, 14 or 16
+        day: u8,
+        /// The puzzle input to use instead of the downloaded one, or - to read it from stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// How many steps to show a second
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+        /// When to colour the tiles
+        #[arg(long, value_enum, default_value_t = When::Auto)]
+        colour: When
+    },
+    /// Adds a crate for a new day to the workspace, with a solver to fill in, and registers it with the runner
+    New {
+        /// The day to add
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The puzzle's title
+        #[arg(short, long)]
+        title: String
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// A line per day and then a line per part, with how long each took
+    Text,
+    /// A JSON object per part, one per line, with the day, part, answer, time taken in nanoseconds and input hash
+    Json
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum When {
+    /// Only when printing to a terminal
+    Auto,
+    Always,
+    Never
+}
+
+// A number of seconds above zero, which may have a fraction.
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    let budget = seconds.parse::<f64>().ok().and_then(|seconds| Duration::try_from_secs_f64(seconds).ok());
+    match budget {
+        Some(budget) if !budget.is_zero() => return Ok(budget),
+        _ => return Err(format!("expected a number of seconds above 0, found `{seconds}`"))
+    }
+}
+
+// A number of runs above zero, so that there is always a timing to report.
+fn parse_samples(samples: &str) -> Result<usize, String> {
+    match samples.parse::<usize>() {
+        Ok(samples) if samples > 0 => return Ok(samples),
+        _ => return Err(format!("expected a number of samples above 0, found `{samples}`"))
+    }
+}
+
+// Every day, or just `day` if it has a solver.
+fn days_to_solve(day: Option<u8>) -> Option<Vec<u8>> {
+    match day {
+        Some(day) if !calendar::DAYS.contains(&day) => {
+            eprintln!("There is no solver for day {day}.");
+            return None;
+        },
+        Some(day) => return Some(vec![day]),
+        None => return Some(calendar::DAYS.to_vec())
+    }
+}
+
+fn read_input(input_path: &Path) -> io::Result<String> {
+    if input_path == Path::new("-") {
+        return io::read_to_string(io::stdin());
+    }
+    return fs::read_to_string(input_path);
+}
+
+fn run(config: &Config, day: Option<u8>, part: Option<u8>, input: Option<PathBuf>, format: Format, budget: Option<Duration>) -> ExitCode {
+    let parts: Vec<Part> = match part {
+        Some(part) => vec![Part::try_from(part).unwrap()],
+        None => Part::BOTH.to_vec()
+    };
+
+    let single_day = day.is_some();
+    let Some(days) = days_to_solve(day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let answers_path = answers::default_answers_path();
+    let answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Every input is read, and downloaded if need be, before any day is solved. Downloads go one at a time to spare
+    // the site, while the days may be solved all at once.
+    let inputs = config.input_manager();
+    let mut to_solve: Vec<(u8, String)> = Vec::new();
+    let mut input_paths: Vec<PathBuf> = Vec::new();
+    for day in days {
+        let input_path = match &input {
+            Some(input) => input.clone(),
+            None => match inputs.resolve(day) {
+                Ok(input_path) => input_path,
+                Err(error) => {
+                    // Running every day skips the ones without an input, but a day asked for by name has to run.
+                    eprintln!("Skipping day {day}: {error}");
+                    if single_day {
+                        return ExitCode::FAILURE;
+                    }
+                    continue;
+                }
+            }
+        };
+        let file_contents = match read_input(&input_path) {
+            Ok(file_contents) => file_contents,
+            Err(error) => {
+                eprintln!("Skipping day {day}: could not read {}: {error}", input_path.display());
+                // Running every day skips the ones without an input, but a day asked for by name has to run.
+                if single_day {
+                    return ExitCode::FAILURE;
+                }
+                continue;
+            }
+        };
+        to_solve.push((day, file_contents));
+        input_paths.push(input_path);
+    }
+
+    let mut failed = false;
+    let reports = runner::run_all(&to_solve, &parts, config, budget);
+    for (((day, file_contents), input_path), result) in to_solve.iter().zip(&input_paths).zip(reports) {
+        match result {
+            Ok(report) => {
+                let input_hash = answers::input_hash(file_contents);
+                match format {
+                    Format::Text => print!("{report}"),
+                    Format::Json => print!("{}", report.to_json_lines(&input_hash))
+                }
+                // A part that times out is reported like any other, but it still fails the run.
+                if report.parts.iter().any(|part| part.answer.is_none()) {
+                    failed = true;
+                }
+                // A refactor that changes an answer the site already accepted is a regression. With params of its own,
+                // the day is solving a different puzzle from the one the site accepted the answers to.
+                if config.params.contains_key(day) {
+                    continue;
+                }
+                for mismatch in answers.check(&input_hash, &report) {
+                    eprintln!("{mismatch} (recorded in {})", answers_path.display());
+                    failed = true;
+                }
+            },
+            Err(error) => {
+                // A broken input should not stop the other days from running, but it still fails the run.
+                eprintln!("Day {day}: {}", error.in_file(input_path));
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn bench(config: &Config, day: Option<u8>, inputs: Option<PathBuf>, samples: usize, json: Option<PathBuf>, markdown: Option<PathBuf>) -> ExitCode {
+    let single_day = day.is_some();
+    let Some(days) = days_to_solve(day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let input_manager = config.input_manager();
+    let mut report = BenchReport { samples, days: Vec::new() };
+    let mut failed = false;
+    for day in days {
+        let input_path = match &inputs {
+            Some(inputs) => inputs.join(format!("day{day:02}.txt")),
+            None => match input_manager.resolve(day) {
+                Ok(input_path) => input_path,
+                Err(error) => {
+                    eprintln!("Skipping day {day}: {error}");
+                    if single_day {
+                        return ExitCode::FAILURE;
+                    }
+                    continue;
+                }
+            }
+        };
+        let file_contents = match read_input(&input_path) {
+            Ok(file_contents) => file_contents,
+            Err(error) => {
+                eprintln!("Skipping day {day}: could not read {}: {error}", input_path.display());
+                if single_day {
+                    return ExitCode::FAILURE;
+                }
+                continue;
+            }
+        };
+
+        match calendar::visit(day, Bench { input: &file_contents, samples }).unwrap() {
+            Ok(benchmark) => report.days.push(benchmark),
+            Err(error) => {
+                eprintln!("Day {day}: {}", error.in_file(&input_path));
+                failed = true;
+            }
+        }
+    }
+
+    print!("{}", report.to_markdown());
+    for (path, contents) in [(json, report.to_json()), (markdown, report.to_markdown())] {
+        let Some(path) = path else {
+            continue;
+        };
+        if let Err(error) = fs::write(&path, contents) {
+            eprintln!("Could not write {}: {error}", path.display());
+            failed = true;
+        }
+    }
+
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn fetch(config: &Config, day: Option<u8>, force: bool) -> ExitCode {
+    let Some(days) = days_to_solve(day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let inputs = config.input_manager();
+    for day in days {
+        if !force && inputs.find(day).is_some() {
+            continue;
+        }
+        match inputs.fetch(day) {
+            Ok(_) => println!("Downloaded day {day} to {}", inputs.cached_path(day).display()),
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    return ExitCode::SUCCESS;
+}
+
+// The answer the solver gives for one part of `day` with its downloaded input. The params in the config are left out,
+// since the site wants the answer to the puzzle as it was set.
+fn solve(config: &Config, day: u8, part: Part) -> Option<String> {
+    days_to_solve(Some(day))?;
+    let input_path = match config.input_manager().resolve(day) {
+        Ok(input_path) => input_path,
+        Err(error) => {
+            eprintln!("{error}");
+            return None;
+        }
+    };
+    let file_contents = match read_input(&input_path) {
+        Ok(file_contents) => file_contents,
+        Err(error) => {
+            eprintln!("Could not read {}: {error}", input_path.display());
+            return None;
+        }
+    };
+
+    match calendar::visit(day, Run { input: &file_contents, parts: &[part], params: &Params::new(), budget: None }).unwrap() {
+        Ok(mut report) => {
+            let answer = report.parts.remove(0).answer;
+            if answer.as_deref() == Some(NO_ANSWER) {
+                eprintln!("Day {day}, part {part} has no answer for {}.", input_path.display());
+                return None;
+            }
+            return answer;
+        },
+        Err(error) => {
+            eprintln!("Day {day}: {}", error.in_file(&input_path));
+            return None;
+        }
+    }
+}
+
+// Records an answer the site accepted against the input it was computed from, so later runs can check it.
+fn record_answer(config: &Config, day: u8, part: Part, answer: &str) -> ExitCode {
+    let Some(input_path) = config.input_manager().find(day) else {
+        eprintln!("Not recording the answer, since there is no input for day {day} to record it against.");
+        return ExitCode::SUCCESS;
+    };
+    let file_contents = match read_input(&input_path) {
+        Ok(file_contents) => file_contents,
+        Err(error) => {
+            eprintln!("Could not read {}: {error}", input_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let answers_path = answers::default_answers_path();
+    let recorded = Answers::load(&answers_path).and_then(|mut answers| {
+        answers.record(day, &answers::input_hash(&file_contents), part, answer);
+        return answers.save(&answers_path);
+    });
+    match recorded {
+        Ok(()) => {
+            println!("Recorded the answer in {}.", answers_path.display());
+            return ExitCode::SUCCESS;
+        },
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+}
+
+fn submit(config: &Config, day: u8, part: u8, answer: Option<String>) -> ExitCode {
+    let part = Part::try_from(part).unwrap();
+    let Some(answer) = answer.or_else(|| solve(config, day, part)) else {
+        return ExitCode::FAILURE;
+    };
+
+    println!("Day {day:02}, part {part}: sending {answer}");
+    match config.submitter().submit(day, part, &answer, SystemTime::now()) {
+        Ok(response) => {
+            match response.wait {
+                Some(wait) => println!("The site says {}. Wait {}s before the next answer.", response.verdict, wait.as_secs()),
+                None => println!("The site says {}.", response.verdict)
+            }
+            if response.verdict != Verdict::Correct {
+                return ExitCode::FAILURE;
+            }
+            return record_answer(config, day, part, &answer);
+        },
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+}
+
+fn gen(day: u8, seed: u64, size: Size, output: Option<PathBuf>) -> ExitCode {
+    let Some(input) = aoc_gen::generate(day, seed, &size) else {
+        eprintln!("There is no generator for day {day}.");
+        return ExitCode::FAILURE;
+    };
+
+    let Some(output) = output else {
+        print!("{input}");
+        return ExitCode::SUCCESS;
+    };
+    if let Err(error) = fs::write(&output, input) {
+        eprintln!("Could not write {}: {error}", output.display());
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn render(config: &Config, day: u8, input: Option<PathBuf>, output: Option<PathBuf>, cell_size: u32) -> ExitCode {
+    if !render::DAYS.contains(&day) {
+        eprintln!("There is no renderer for day {day}.");
+        return ExitCode::FAILURE;
+    }
+    let input_path = match input {
+        Some(input) => input,
+        None => match config.input_manager().resolve(day) {
+            Ok(input_path) => input_path,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let file_contents = match read_input(&input_path) {
+        Ok(file_contents) => file_contents,
+        Err(error) => {
+            eprintln!("Could not read {}: {error}", input_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let picture = match render::render(day, &file_contents).unwrap() {
+        Ok(picture) => picture,
+        Err(error) => {
+            eprintln!("Day {day}: {}", error.in_file(&input_path));
+            return ExitCode::FAILURE;
+        }
+    };
+    let output = output.unwrap_or_else(|| PathBuf::from(format!("day{day:02}.svg")));
+    if let Err(error) = picture.save(&output, cell_size) {
+        eprintln!("Could not write {}: {error}", output.display());
+        return ExitCode::FAILURE;
+    }
+    println!("Drew day {day} to {}", output.display());
+    return ExitCode::SUCCESS;
+}
+
+fn animate(config: &Config, day: u8, input: Option<PathBuf>, fps: f64, colour: When) -> ExitCode {
+    if !animate::DAYS.contains(&day) {
+        eprintln!("There is no animation for day {day}.");
+        return ExitCode::FAILURE;
+    }
+    let input_path = match input {
+        Some(input) => input,
+        None => match config.input_manager().resolve(day) {
+            Ok(input_path) => input_path,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let file_contents = match read_input(&input_path) {
+        Ok(file_contents) => file_contents,
+        Err(error) => {
+            eprintln!("Could not read {}: {error}", input_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // On a terminal each frame is drawn over the last. Anywhere else the frames are printed one after another.
+    let terminal = io::stdout().is_terminal();
+    let colour = match colour {
+        When::Auto => terminal,
+        When::Always => true,
+        When::Never => false
+    };
+    let delay = Duration::from_secs_f64(1.0 / fps.max(0.001));
+    if terminal {
+        print!("\x1b[2J");
+    }
+    let mut step = 0;
+    let animated = animate::animate(day, &file_contents, |frame| {
+        if terminal {
+            print!("\x1b[H");
+        }
+        println!("Day {day:02}, step {step}");
+        print!("{}", animate::to_ansi(frame, colour));
+        if terminal {
+            io::stdout().flush().unwrap();
+            thread::sleep(delay);
+        } else {
+            println!();
+        }
+        step += 1;
+    });
+
+    if let Err(error) = animated.unwrap() {
+        eprintln!("Day {day}: {}", error.in_file(&input_path));
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn new(day: u8, title: String) -> ExitCode {
+    // The workspace this binary was built from, which is the one being worked on.
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    match (Scaffold { day, title }).create(root) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.strip_prefix(root).unwrap_or(&path).display());
+            }
+            println!("Paste the example into TEST_INPUT in day{day:02}/src/lib.rs and its answers into the tests, then solve away.");
+            return ExitCode::SUCCESS;
+        },
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+}
+
+// Logs to stderr, so that the answers on stdout stay clean. `RUST_LOG` takes over from `-v` when it is set, which is
+// how to turn on logging for one day only, e.g. `RUST_LOG=day19=trace`.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace"
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter).with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    let config_path = config::default_config_path();
+    let config = match Config::load(&config_path) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.command {
+        Command::Run { day, part, input, format, timeout } => run(&config, day, part, input, format, timeout),
+        Command::Bench { day, inputs, samples, json, markdown } => bench(&config, day, inputs, samples, json, markdown),
+        Command::Fetch { day, force } => fetch(&config, day, force),
+        Command::Submit { day, part, answer } => submit(&config, day, part, answer),
+        Command::Gen { day, seed, count, width, height, output } => gen(day, seed, Size { count, width, height }, output),
+        Command::Render { day, input, output, cell_size } => render(&config, day, input, output, cell_size),
+        Command::Animate { day, input, fps, colour } => animate(&config, day, input, fps, colour),
+        Command::New { day, title } => new(day, title),
+    }
+}
@@ -0,0 +1,218 @@
+//--------------------------------------------------------------------------------
+// Render: Pictures of what the solvers found
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Draws a day's input together with what its solver found in it, for the days whose puzzles are maps:
+//!
+//! - Day 10: the main loop, and the tiles it encloses.
+//! - Day 14: the platform as given, tilted north, after one spin cycle, and after a billion of them.
+//! - Day 16: the tiles the beam energizes, and the path of every beam through the contraption.
+//! - Day 17: the heat map, with the best path for the crucible in part one and the ultra crucible in part two.
+//! - Day 18: the lagoon dug out by the plan in part one, with the trench painted in its colours.
+
+use std::collections::HashSet;
+
+use aoc_common::ParseError;
+use aoc_grid::{Grid, Pos};
+use aoc_render::{Colour, Picture, Stroke};
+
+/// Every day that can be rendered.
+pub const DAYS: &[u8] = &[10, 14, 16, 17, 18];
+
+const GROUND: Colour = Colour::from_hex(0xf4f1e8);
+pub(crate) const STONE: Colour = Colour::from_hex(0x4a4e5a);
+pub(crate) const FADED: Colour = Colour::from_hex(0xc9c5b9);
+pub(crate) const PIPE: Colour = Colour::from_hex(0x2b6cb0);
+const ENCLOSED: Colour = Colour::from_hex(0x9ae6b4);
+pub(crate) const ROCK: Colour = Colour::from_hex(0xdd6b20);
+pub(crate) const ENERGIZED: Colour = Colour::from_hex(0xfbd38d);
+pub(crate) const BEAM: Colour = Colour::from_hex(0xe53e3e);
+const COOL: Colour = Colour::from_hex(0xfff5eb);
+const HOT: Colour = Colour::from_hex(0x9c2a00);
+const CRUCIBLE: Colour = Colour::from_hex(0x2b6cb0);
+const ULTRA_CRUCIBLE: Colour = Colour::from_hex(0x38a169);
+const LAGOON: Colour = Colour::from_hex(0x90cdf4);
+
+/// The picture of `day` for `input`, or `None` if the day cannot be rendered.
+pub fn render(day: u8, input: &str) -> Option<Result<Picture, ParseError>> {
+    let picture = match day {
+        10 => day10(input),
+        14 => day14(input),
+        16 => day16(input),
+        17 => day17(input),
+        18 => day18(input),
+        _ => return None
+    };
+    return Some(picture);
+}
+
+fn day10(input: &str) -> Result<Picture, ParseError> {
+    let map = day10::Map::parse(input)?;
+    let mut picture = Picture::from_grid(&map.grid, |tile| {
+        match tile {
+            day10::Legend::Ground => return GROUND,
+            _ => return FADED
+        }
+    });
+
+    for position in map.interior_tiles() {
+        picture.fill(position, ENCLOSED);
+    }
+    let mut main_loop = map.dfs_ordered_by_traversal();
+    for &position in &main_loop {
+        picture.fill(position, GROUND);
+    }
+    main_loop.push(map.start);
+    picture.path(&main_loop, PIPE, 0.3);
+    return Ok(picture);
+}
+
+fn day14(input: &str) -> Result<Picture, ParseError> {
+    let map = day14::Map::parse(input)?;
+    let panel = |map: &day14::Map| {
+        return Picture::from_grid(&map.grid, |&tile| {
+            match tile {
+                'O' => return ROCK,
+                '#' => return STONE,
+                _ => return GROUND
+            }
+        });
+    };
+
+    let mut tilted = map.clone();
+    tilted.slide_north();
+    let mut spun = map.clone();
+    spun.spin();
+    let mut spun_a_billion_times = map.clone();
+    spun_a_billion_times.spin_cycle(None);
+    return Ok(Picture::beside(&[panel(&map), panel(&tilted), panel(&spun), panel(&spun_a_billion_times)], 1, Colour::WHITE));
+}
+
+fn day16(input: &str) -> Result<Picture, ParseError> {
+    let cave = day16::Cave::parse(input)?;
+    let energized = cave.trace_beams(None);
+    let mut picture = Picture::from_grid(&cave.grid, |tile| {
+        match tile {
+            day16::MapElement::Passthrough => return GROUND,
+            day16::MapElement::Mirror { .. } => return STONE
+        }
+    });
+    for position in energized {
+        if matches!(cave.grid[position], day16::MapElement::Passthrough) {
+            picture.fill(position, ENERGIZED);
+        }
+    }
+
+    // Each beam entered its tile from the one behind it, or from outside the contraption for the first beam.
+    let mut segments: HashSet<day16::Beam> = HashSet::new();
+    for beam in cave.beam_fronts(None).flatten() {
+        if !segments.insert(beam.clone()) {
+            continue;
+        }
+        let (dx, dy) = beam.direction.delta();
+        let (x, y) = (beam.position.x as f64 + 0.5, beam.position.y as f64 + 0.5);
+        picture.strokes.push(Stroke { points: vec![(x - dx as f64, y - dy as f64), (x, y)], colour: BEAM, width: 0.15 });
+    }
+    return Ok(picture);
+}
+
+fn day17(input: &str) -> Result<Picture, ParseError> {
+    let city = day17::City::parse(input)?;
+    let mut picture = Picture::from_grid(&city.map, |&heat_loss| COOL.mix(HOT, (heat_loss as f64 - 1.0) / 8.0));
+
+    // A city too small for the ultra crucible only has the path of the plain one.
+    if let Some((path, _)) = city.least_heat_loss_path(None, None) {
+        picture.path(&path, CRUCIBLE, 0.3);
+    }
+    if let Some((path, _)) = city.least_heat_loss_path(Some(4), Some(10)) {
+        picture.path(&path, ULTRA_CRUCIBLE, 0.3);
+    }
+    return Ok(picture);
+}
+
+fn day18(input: &str) -> Result<Picture, ParseError> {
+    let plan = day18::DigPlan::parse(input)?;
+
+    // The trench goes left of and above where it starts, so dig it out on a plan of its own first.
+    let mut trench: Vec<((i64, i64), Colour)> = Vec::new();
+    let mut current: (i64, i64) = (0, 0);
+    for step in &plan.plan {
+        let (dx, dy) = step.direction.delta();
+        for _ in 0..step.steps {
+            current = (current.0 + dx as i64, current.1 + dy as i64);
+            trench.push((current, Colour::from_hex(step.colour)));
+        }
+    }
+
+    // A border of ground all round lets the ground outside be flooded from one corner.
+    let left = trench.iter().map(|((x, _), _)| *x).min().unwrap_or(0) - 1;
+    let top = trench.iter().map(|((_, y), _)| *y).min().unwrap_or(0) - 1;
+    let width = (trench.iter().map(|((x, _), _)| *x).max().unwrap_or(0) - left + 2) as usize;
+    let height = (trench.iter().map(|((_, y), _)| *y).max().unwrap_or(0) - top + 2) as usize;
+
+    let mut picture = Picture::new(width, height, LAGOON);
+    let mut dug: Grid<bool> = Grid::filled(width, height, false);
+    for ((x, y), colour) in trench {
+        let position = Pos::new((x - left) as usize, (y - top) as usize);
+        picture.fill(position, colour);
+        dug[position] = true;
+    }
+
+    let mut outside: Vec<Pos> = vec![Pos::new(0, 0)];
+    let mut flooded: Grid<bool> = Grid::filled(width, height, false);
+    flooded[Pos::new(0, 0)] = true;
+    while let Some(position) = outside.pop() {
+        picture.fill(position, GROUND);
+        for neighbour in dug.neighbours4(position) {
+            if !dug[neighbour] && !flooded[neighbour] {
+                flooded[neighbour] = true;
+                outside.push(neighbour);
+            }
+        }
+    }
+    return Ok(picture);
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_gen::Size;
+    use aoc_grid::Pos;
+
+    use crate::render::{render, DAYS, ENCLOSED, GROUND, LAGOON};
+
+    #[test]
+    fn test_render_generated_inputs() {
+        let size = Size { count: 8, width: 12, height: 9 };
+        for &day in DAYS {
+            let input = aoc_gen::generate(day, 3, &size).unwrap();
+            let picture = render(day, &input).unwrap().unwrap();
+            assert!(picture.width() > 0 && picture.height() > 0, "day {day}");
+            assert!(picture.to_svg(4).starts_with("<svg"), "day {day}");
+            assert!(!picture.to_png(2).is_empty(), "day {day}");
+        }
+        assert!(render(1, "1abc2\n").is_none());
+        assert!(render(17, "12\nx4\n").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_render_day10() {
+        let input = "..........\n.S------7.\n.|F----7|.\n.||....||.\n.||....||.\n.|L-7F-J|.\n.|..||..|.\n.L--JL--J.\n..........\n";
+        let picture = render(10, input).unwrap().unwrap();
+        let enclosed = picture.cells.iter().filter(|(_, &colour)| colour == ENCLOSED).count();
+        assert_eq!(enclosed, 4);
+        // The 44 tiles of the loop, and back to the start.
+        assert_eq!(picture.strokes[0].points.len(), 45);
+    }
+
+    #[test]
+    fn test_render_day18() {
+        let input = "R 2 (#ff0000)\nD 2 (#00ff00)\nL 2 (#0000ff)\nU 2 (#ffffff)\n";
+        let picture = render(18, input).unwrap().unwrap();
+        assert_eq!((picture.width(), picture.height()), (5, 5));
+        assert_eq!(picture.cells[Pos::new(0, 0)], GROUND);
+        assert_eq!(picture.cells[Pos::new(2, 2)], LAGOON);
+        assert_eq!(picture.cells[Pos::new(2, 1)].to_hex(), "#ff0000");
+        assert_eq!(picture.cells[Pos::new(1, 1)].to_hex(), "#ffffff");
+    }
+}
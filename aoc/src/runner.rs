@@ -0,0 +1,267 @@
+//--------------------------------------------------------------------------------
+// Runner: Solves a day and times each step
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use aoc_common::{Cancellation, Params, ParseError, Part, Solver};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::Serialize;
+use tracing::{debug, info_span};
+
+use crate::calendar::{self, Visitor};
+use crate::config::Config;
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    /// The answer, or `None` if the part ran out of time before finding it.
+    pub answer: Option<String>,
+    pub duration: Duration
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: &'static str,
+    pub parse_duration: Duration,
+    pub parts: Vec<PartReport>
+}
+
+/// One solved part, as `aoc run --format json` prints it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AnswerRecord {
+    pub day: u8,
+    pub part: u8,
+    /// `null` if the part ran out of time.
+    pub answer: Option<String>,
+    pub duration_ns: u64,
+    /// The hash of the input the answer is for, as in `answers.toml`.
+    pub input_hash: String
+}
+
+impl DayReport {
+    /// A record of each part solved, for the input with hash `input_hash`.
+    pub fn records(&self, input_hash: &str) -> Vec<AnswerRecord> {
+        return self.parts.iter()
+            .map(|part| AnswerRecord {
+                day: self.day,
+                part: u8::from(part.part),
+                answer: part.answer.clone(),
+                duration_ns: part.duration.as_nanos() as u64,
+                input_hash: input_hash.to_string()
+            })
+            .collect();
+    }
+
+    /// The records as JSON lines: one object per part, each on its own line.
+    pub fn to_json_lines(&self, input_hash: &str) -> String {
+        return self.records(input_hash).iter().map(|record| serde_json::to_string(record).unwrap() + "\n").collect();
+    }
+}
+
+// The text format is kept stable so that it can be compared between runs: a header line for the day, then a line
+// for each part, indented by four spaces.
+impl Display for DayReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Day {:02}: {} (parsed in {:.2?})", self.day, self.title, self.parse_duration)?;
+        for part in &self.parts {
+            match &part.answer {
+                Some(answer) => writeln!(f, "    Part {}: {} ({:.2?})", part.part, answer, part.duration)?,
+                None => writeln!(f, "    Part {}: timed out ({:.2?})", part.part, part.duration)?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Solves `parts` of the day with `params`, giving each part up to `budget` if there is one. A part that runs out of time is reported
+/// without an answer, but only solvers that check their [`Cancellation`] can be stopped early.
+/// Whatever the solver logs is grouped under a `day` span, and then a `part` span for each part.
+pub fn run<S: Solver>(input: &str, parts: &[Part], params: &Params, budget: Option<Duration>) -> Result<DayReport, ParseError> {
+    let _day = info_span!("day", day = S::DAY).entered();
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_duration = start.elapsed();
+    debug!(duration = ?parse_duration, "parsed the input");
+
+    let parts = parts.iter()
+                    .map(|&part| {
+                        let _part = info_span!("part", part = %part).entered();
+                        let cancellation = match budget {
+                            Some(budget) => Cancellation::with_budget(budget),
+                            None => Cancellation::new()
+                        };
+                        let start = Instant::now();
+                        let answer = S::solve_cancellable(&parsed, part, params, &cancellation).ok();
+                        let duration = start.elapsed();
+                        match &answer {
+                            Some(answer) => debug!(answer, ?duration, "solved"),
+                            None => debug!(?duration, "ran out of time")
+                        }
+                        PartReport { part, answer, duration }
+                    })
+                    .collect();
+
+    Ok(DayReport { day: S::DAY, title: S::TITLE, parse_duration, parts })
+}
+
+/// Runs whichever solver it is handed on the given input.
+pub struct Run<'a> {
+    pub input: &'a str,
+    pub parts: &'a [Part],
+    /// What to change of the puzzle's own constants.
+    pub params: &'a Params,
+    /// How long each part may take, if not forever.
+    pub budget: Option<Duration>
+}
+
+impl Visitor for Run<'_> {
+    type Output = Result<DayReport, ParseError>;
+
+    fn visit<S: Solver>(self) -> Self::Output {
+        return run::<S>(self.input, self.parts, self.params, self.budget);
+    }
+}
+
+/// Solves each day in `inputs` with its input and its params in `config`, and gives back the results in the same order.
+/// With the `parallel` feature, the days are solved at the same time and the results only come back once all are done;
+/// otherwise each comes back as soon as it is solved.
+pub fn run_all<'a>(inputs: &'a [(u8, String)], parts: &'a [Part], config: &'a Config, budget: Option<Duration>) -> impl Iterator<Item = Result<DayReport, ParseError>> + 'a {
+    let solve = move |(day, input): &'a (u8, String)| {
+        return calendar::visit(*day, Run { input, parts, params: config.params(*day), budget }).unwrap();
+    };
+    #[cfg(feature = "parallel")]
+    return inputs.par_iter().map(solve).collect::<Vec<_>>().into_iter();
+    #[cfg(not(feature = "parallel"))]
+    return inputs.iter().map(solve);
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Params, Part};
+    use aoc_gen::Size;
+
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    use crate::calendar;
+    use crate::config::Config;
+    use crate::runner::{run_all, DayReport, PartReport, Run};
+
+    #[test]
+    fn test_run() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        let report = calendar::visit(1, Run { input, parts: &[Part::One], params: &Params::new(), budget: None }).unwrap().unwrap();
+
+        assert_eq!(report.day, 1);
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].answer.as_deref(), Some("142"));
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let report = DayReport {
+            day: 1,
+            title: "Trebuchet?!",
+            parse_duration: Duration::from_micros(12),
+            parts: vec![
+                PartReport { part: Part::One, answer: Some("142".to_string()), duration: Duration::from_nanos(3400) },
+                PartReport { part: Part::Two, answer: Some("281".to_string()), duration: Duration::from_millis(2) }
+            ]
+        };
+
+        assert_eq!(report.to_string(), "\
+Day 01: Trebuchet?! (parsed in 12.00µs)
+    Part 1: 142 (3.40µs)
+    Part 2: 281 (2.00ms)
+");
+        assert_eq!(report.to_json_lines("00ff"), "\
+{\"day\":1,\"part\":1,\"answer\":\"142\",\"duration_ns\":3400,\"input_hash\":\"00ff\"}
+{\"day\":1,\"part\":2,\"answer\":\"281\",\"duration_ns\":2000000,\"input_hash\":\"00ff\"}
+");
+    }
+
+    #[test]
+    fn test_run_out_of_time() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        let mut report = calendar::visit(1, Run { input, parts: &Part::BOTH, params: &Params::new(), budget: Some(Duration::ZERO) }).unwrap().unwrap();
+        assert!(report.parts.iter().all(|part| part.answer.is_none()));
+
+        report.parse_duration = Duration::from_micros(12);
+        for part in &mut report.parts {
+            part.duration = Duration::from_nanos(150);
+        }
+        assert_eq!(report.to_string(), "\
+Day 01: Trebuchet?! (parsed in 12.00µs)
+    Part 1: timed out (150.00ns)
+    Part 2: timed out (150.00ns)
+");
+        assert!(report.to_json_lines("00ff").starts_with("{\"day\":1,\"part\":1,\"answer\":null,"));
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        let input = "1abc2\npqr3 stu8vwx\n";
+        let error = calendar::visit(1, Run { input, parts: &[Part::One], params: &Params::new(), budget: None }).unwrap().unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+
+    #[test]
+    fn test_run_all() {
+        // A day only just added with `aoc new` has no generator yet.
+        let inputs: Vec<(u8, String)> = calendar::DAYS.iter()
+            .filter_map(|&day| Some((day, aoc_gen::generate(day, 1, &Size::default())?)))
+            .collect();
+        let reports: Vec<DayReport> = run_all(&inputs, &Part::BOTH, &Config::default(), None).map(|report| report.unwrap()).collect();
+        assert_eq!(reports.len(), inputs.len());
+
+        // Whether the days run one after the other or all at once, each gives the same answers as running it alone.
+        for ((day, input), report) in inputs.iter().zip(reports) {
+            let alone = calendar::visit(*day, Run { input, parts: &Part::BOTH, params: &Params::new(), budget: None }).unwrap().unwrap();
+            assert_eq!(report.day, *day);
+            assert_eq!(
+                report.parts.iter().map(|part| &part.answer).collect::<Vec<_>>(),
+                alone.parts.iter().map(|part| &part.answer).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_all_with_params() {
+        let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+        let inputs: Vec<(u8, String)> = vec![(2, input.to_string())];
+        let mut bag = Params::new();
+        bag.set("bag.red", 20);
+        let config = Config { params: BTreeMap::from([(2, bag)]), ..Config::default() };
+
+        let answers: Vec<Option<String>> = run_all(&inputs, &[Part::One], &Config::default(), None).map(|report| report.unwrap().parts[0].answer.clone()).collect();
+        assert_eq!(answers, vec![Some("8".to_string())]);
+        let answers: Vec<Option<String>> = run_all(&inputs, &[Part::One], &config, None).map(|report| report.unwrap().parts[0].answer.clone()).collect();
+        assert_eq!(answers, vec![Some("11".to_string())]);
+    }
+
+    #[test]
+    fn test_run_generated() {
+        let sizes = [Size::default(), Size { count: 3, width: 4, height: 3 }, Size { count: 30, width: 25, height: 20 }];
+        for &day in calendar::DAYS {
+            for (seed, size) in sizes.iter().enumerate() {
+                let Some(input) = aoc_gen::generate(day, seed as u64, size) else {
+                    continue;
+                };
+                let result = calendar::visit(day, Run { input: &input, parts: &Part::BOTH, params: &Params::new(), budget: None }).unwrap();
+                assert!(result.is_ok(), "day {day} with {size:?}: {}\n{input}", result.unwrap_err());
+            }
+        }
+    }
+}
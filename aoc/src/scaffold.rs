@@ -0,0 +1,216 @@
+//--------------------------------------------------------------------------------
+// Scaffold: A new day from the template, registered everywhere it needs to be
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Adding a day by hand means copying a crate, renaming it throughout and remembering the three places that list the
+//! days. [`Scaffold::create`] does all of it from the templates in `aoc/templates`:
+//!
+//! - `dayNN/Cargo.toml`, `dayNN/src/main.rs` and `dayNN/src/lib.rs`, with the standard header, a `Solver` stub that
+//!   reports the day as not solved yet, and ignored tests with an empty `TEST_INPUT` for the puzzle's example.
+//! - The crate as a member of the workspace, a dependency of `aoc`, and a day of the calendar, which is what the
+//!   runner and the benchmarks go through.
+
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day already has a crate.
+    Exists { path: PathBuf },
+    Io { path: PathBuf, error: io::Error },
+    /// A file that lists the days could not have the new one added to it.
+    Register { path: PathBuf, message: String }
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists { path } => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            ScaffoldError::Register { path, message } => write!(f, "{}: {message}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// A file that lists the days, the line that lists the new one, and how to find the day a line lists, if any.
+type Listing = (PathBuf, String, fn(&str) -> Option<u8>);
+
+/// A day to add to the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffold {
+    pub day: u8,
+    /// The puzzle's title, as the site writes it.
+    pub title: String
+}
+
+impl Scaffold {
+    /// The files of the day's crate, relative to the root of the workspace, and what goes in them.
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        let directory = PathBuf::from(format!("day{:02}", self.day));
+        return vec![
+            (directory.join("Cargo.toml"), self.fill(CARGO_TEMPLATE)),
+            (directory.join("src").join("main.rs"), self.fill(MAIN_TEMPLATE)),
+            (directory.join("src").join("lib.rs"), self.fill(LIB_TEMPLATE))
+        ];
+    }
+
+    fn fill(&self, template: &str) -> String {
+        return template
+            .replace("{{day}}", &format!("{:02}", self.day))
+            .replace("{{number}}", &self.day.to_string())
+            .replace("{{title}}", &self.title);
+    }
+
+    /// Writes the day's crate into the workspace at `root` and registers it, returning every file written or changed.
+    /// Nothing is written if the crate already exists or the day cannot be registered.
+    pub fn create(&self, root: &Path) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let directory = root.join(format!("day{:02}", self.day));
+        if directory.exists() {
+            return Err(ScaffoldError::Exists { path: directory });
+        }
+
+        // Every listing is worked out before anything is written, so a listing that cannot take the day leaves the
+        // workspace as it was.
+        let registrations = self.registrations(root)?;
+
+        let mut written: Vec<PathBuf> = Vec::new();
+        for (path, contents) in self.files().into_iter().chain(registrations) {
+            let path = root.join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|error| ScaffoldError::Io { path: parent.to_path_buf(), error })?;
+            }
+            fs::write(&path, contents).map_err(|error| ScaffoldError::Io { path: path.clone(), error })?;
+            written.push(path);
+        }
+        return Ok(written);
+    }
+
+    /// The files at `root` that list the days, with the day added to each.
+    fn registrations(&self, root: &Path) -> Result<Vec<(PathBuf, String)>, ScaffoldError> {
+        let crate_name = format!("day{:02}", self.day);
+        let listings: [Listing; 3] = [
+            (PathBuf::from("Cargo.toml"), format!("    \"{crate_name}\","), |line| {
+                return day_of(line.strip_prefix("\"day")?);
+            }),
+            (Path::new("aoc").join("Cargo.toml"), format!("{crate_name} = {{ path = \"../{crate_name}\" }}"), |line| {
+                return day_of(line.strip_prefix("day").filter(|_| line.contains("path"))?);
+            }),
+            (Path::new("aoc").join("src").join("calendar.rs"), format!("    {crate_name}::Day{:02},", self.day), |line| {
+                return day_of(line.strip_prefix("day").filter(|_| line.contains("::Day"))?);
+            })
+        ];
+
+        let mut registrations: Vec<(PathBuf, String)> = Vec::new();
+        for (path, line, listed_day) in listings {
+            let full_path = root.join(&path);
+            let contents = fs::read_to_string(&full_path).map_err(|error| ScaffoldError::Io { path: full_path.clone(), error })?;
+            let contents = insert_in_order(&contents, &line, self.day, |line| listed_day(line.trim_start()))
+                .map_err(|message| ScaffoldError::Register { path: full_path, message })?;
+            registrations.push((path, contents));
+        }
+        return Ok(registrations);
+    }
+}
+
+/// The day at the start of `text`, written with two digits.
+fn day_of(text: &str) -> Option<u8> {
+    return text.get(..2)?.parse().ok();
+}
+
+/// `contents` with `line` added among the lines that `listed_day` finds a day in, keeping them in order of day.
+fn insert_in_order(contents: &str, line: &str, day: u8, listed_day: impl Fn(&str) -> Option<u8>) -> Result<String, String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let listed: Vec<(usize, u8)> = lines.iter().enumerate().filter_map(|(i, line)| Some((i, listed_day(line)?))).collect();
+    if listed.iter().any(|&(_, listed)| listed == day) {
+        return Err(format!("day {day} is already listed"));
+    }
+
+    let index = match listed.iter().rev().find(|&&(_, listed)| listed < day) {
+        Some(&(i, _)) => i + 1,
+        None => match listed.first() {
+            Some(&(i, _)) => i,
+            None => return Err("found no days to list the new one with".to_string())
+        }
+    };
+    lines.insert(index, line);
+
+    let mut inserted = lines.join("\n");
+    if contents.ends_with('\n') {
+        inserted.push('\n');
+    }
+    return Ok(inserted);
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::scaffold::{insert_in_order, Scaffold, ScaffoldError};
+
+    #[test]
+    fn test_files() {
+        let scaffold = Scaffold { day: 20, title: "Pulse Propagation".to_string() };
+        let files = scaffold.files();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0].0, Path::new("day20").join("Cargo.toml"));
+        assert!(files[0].1.contains("name = \"day20\""));
+        assert!(files[1].1.contains("aoc_common::solve_file::<day20::Day20>(FILE_NAME);"));
+
+        let lib = &files[2].1;
+        assert!(lib.starts_with("//--------------------------------------------------------------------------------\n// Day 20: Pulse Propagation\n"));
+        assert!(lib.contains("impl Solver for Day20 {\n    const DAY: u8 = 20;\n    const TITLE: &'static str = \"Pulse Propagation\";"));
+        assert!(lib.contains("return Err(ParseError::new(1, 1, \"day 20 is not solved yet\"));"));
+        assert!(lib.contains("const TEST_INPUT: &str = \"\\\n\";"));
+        assert!(!lib.contains("{{"));
+
+        assert!(Scaffold { day: 7, title: String::new() }.files()[2].1.contains("const DAY: u8 = 7;"));
+    }
+
+    #[test]
+    fn test_insert_in_order() {
+        let listed_day = |line: &str| line.strip_prefix("day")?.parse().ok();
+        assert_eq!(insert_in_order("a\nday01\nday03\nb\n", "day02", 2, listed_day), Ok("a\nday01\nday02\nday03\nb\n".to_string()));
+        assert_eq!(insert_in_order("a\nday01\nday03\nb", "day04", 4, listed_day), Ok("a\nday01\nday03\nday04\nb".to_string()));
+        assert_eq!(insert_in_order("a\nday03\n", "day01", 1, listed_day), Ok("a\nday01\nday03\n".to_string()));
+        assert!(insert_in_order("a\nday03\n", "day03", 3, listed_day).is_err());
+        assert!(insert_in_order("a\nb\n", "day03", 3, listed_day).is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        for listing in [Path::new("Cargo.toml").to_path_buf(), Path::new("aoc").join("Cargo.toml"), Path::new("aoc").join("src").join("calendar.rs")] {
+            fs::copy(workspace.join(&listing), root.join(&listing)).unwrap();
+        }
+
+        let scaffold = Scaffold { day: 25, title: "Snowverload".to_string() };
+        let written = scaffold.create(&root).unwrap();
+        assert_eq!(written.len(), 6);
+        assert!(root.join("day25").join("src").join("lib.rs").exists());
+
+        let members = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(members.contains("    \"day25\",\n]"));
+        let dependencies = fs::read_to_string(root.join("aoc").join("Cargo.toml")).unwrap();
+        assert!(dependencies.contains("\" }\nday25 = { path = \"../day25\" }\n"));
+        let calendar = fs::read_to_string(root.join("aoc").join("src").join("calendar.rs")).unwrap();
+        assert!(calendar.contains("    day25::Day25,\n];"));
+
+        assert!(matches!(scaffold.create(&root), Err(ScaffoldError::Exists { .. })));
+        fs::remove_dir_all(root.join("day25")).unwrap();
+        assert!(matches!(scaffold.create(&root), Err(ScaffoldError::Register { .. })));
+        assert!(!root.join("day25").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
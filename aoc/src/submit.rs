@@ -0,0 +1,435 @@
+//--------------------------------------------------------------------------------
+// Submit: Sends an answer to the site and remembers what it said
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! The site only allows one answer a minute or so, and waits longer after each wrong one, so every answer sent and what
+//! the site made of it is kept in a history file. An answer already known to be wrong, or known to be too high or too
+//! low from an earlier answer, is refused without asking the site, and so is any answer sent before the cooldown is over.
+
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::inputs::USER_AGENT;
+
+/// How long to wait after a wrong answer when the site does not say.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// What the site made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint of which way.
+    Wrong,
+    /// Not judged, because the last answer was sent too recently.
+    TooRecent,
+    /// Not judged, because the part has already been solved.
+    AlreadySolved
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer"),
+            Verdict::TooHigh => write!(f, "that's not the right answer; it is too high"),
+            Verdict::TooLow => write!(f, "that's not the right answer; it is too low"),
+            Verdict::Wrong => write!(f, "that's not the right answer"),
+            Verdict::TooRecent => write!(f, "an answer was sent too recently"),
+            Verdict::AlreadySolved => write!(f, "this part has already been solved")
+        }
+    }
+}
+
+/// The site's reply to an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How long the site wants before the next answer.
+    pub wait: Option<Duration>
+}
+
+/// The text of the `<article>` in a page, without its tags.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    return text.split_whitespace().collect::<Vec<&str>>().join(" ");
+}
+
+/// Reads a duration like `1m 23s`, `one minute` or `5 minutes`.
+fn parse_wait(wait: &str) -> Option<Duration> {
+    let mut seconds: u64 = 0;
+    let mut amount: Option<u64> = None;
+    for word in wait.split_whitespace() {
+        let digits: String = word.chars().take_while(char::is_ascii_digit).collect();
+        let unit = &word[digits.len()..];
+        if !digits.is_empty() {
+            amount = Some(digits.parse().ok()?);
+        } else if word == "one" || word == "a" {
+            amount = Some(1);
+        }
+        let multiplier = match unit {
+            "h" | "hour" | "hours" => 3600,
+            "m" | "minute" | "minutes" => 60,
+            "s" | "second" | "seconds" => 1,
+            _ => continue
+        };
+        seconds += amount.take()? * multiplier;
+    }
+    if seconds == 0 {
+        return None;
+    }
+    return Some(Duration::from_secs(seconds));
+}
+
+/// Reads the page the site answers a submission with. Returns `None` if it says none of the things it is known to say.
+pub fn parse_response(html: &str) -> Option<Response> {
+    let text = article_text(html);
+    let wait_between = |start: &str, end: &str| {
+        let after_start = &text[text.find(start)? + start.len()..];
+        return parse_wait(&after_start[..after_start.find(end)?]);
+    };
+
+    if text.contains("That's the right answer") {
+        return Some(Response { verdict: Verdict::Correct, wait: None });
+    }
+    if text.contains("You gave an answer too recently") {
+        return Some(Response { verdict: Verdict::TooRecent, wait: wait_between("You have ", " left to wait") });
+    }
+    if text.contains("You don't seem to be solving the right level") {
+        return Some(Response { verdict: Verdict::AlreadySolved, wait: None });
+    }
+    if text.contains("That's not the right answer") {
+        let verdict = if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        let wait = wait_between("wait ", " before trying again").unwrap_or(DEFAULT_COOLDOWN);
+        return Some(Response { verdict, wait: Some(wait) });
+    }
+    return None;
+}
+
+/// One answer that was sent, as kept in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When it was sent, in seconds since the Unix epoch.
+    pub sent_at: u64,
+    /// When the next answer may be sent, in seconds since the Unix epoch.
+    pub wait_until: Option<u64>
+}
+
+/// Every answer sent so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub attempts: Vec<Attempt>
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part has already been solved with this answer, or with another one.
+    AlreadyCorrect { answer: String },
+    /// The same answer was already sent and was wrong.
+    AlreadyTried { verdict: Verdict },
+    /// An earlier answer showed that this one is too high or too low too.
+    OutOfBounds { verdict: Verdict, bound: String },
+    /// The site asked to wait before the next answer.
+    Cooldown { remaining: Duration }
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect { answer } => write!(f, "this part was already solved with {answer}"),
+            Refusal::AlreadyTried { verdict } => write!(f, "this answer was already sent, and {verdict}"),
+            Refusal::OutOfBounds { verdict: Verdict::TooHigh, bound } => write!(f, "{bound} was already too high, so this answer is too"),
+            Refusal::OutOfBounds { bound, .. } => write!(f, "{bound} was already too low, so this answer is too"),
+            Refusal::Cooldown { remaining } => write!(f, "the site asked to wait another {}s before the next answer", remaining.as_secs())
+        }
+    }
+}
+
+impl History {
+    /// The history in `path`, or an empty one if there is no such file.
+    pub fn load(path: &PathBuf) -> io::Result<History> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(error) => return Err(error)
+        };
+        return serde_json::from_str(&contents).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error));
+    }
+
+    pub fn save(&self, path: &PathBuf) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        return fs::write(path, serde_json::to_string_pretty(self).unwrap());
+    }
+
+    pub fn attempts(&self, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        return self.attempts.iter().filter(move |attempt| attempt.day == day && attempt.part == u8::from(part));
+    }
+
+    /// Whether `answer` is worth sending for the part at `now`, from what the site said about the earlier ones.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        if let Some(correct) = self.attempts(day, part).find(|attempt| attempt.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadyCorrect { answer: correct.answer.clone() });
+        }
+
+        let judged = self.attempts(day, part).filter(|attempt| !matches!(attempt.verdict, Verdict::TooRecent | Verdict::AlreadySolved));
+        let value = answer.parse::<i128>().ok();
+        for attempt in judged {
+            if attempt.answer == answer {
+                return Err(Refusal::AlreadyTried { verdict: attempt.verdict });
+            }
+            let (Some(value), Ok(bound)) = (value, attempt.answer.parse::<i128>()) else {
+                continue;
+            };
+            let out_of_bounds = match attempt.verdict {
+                Verdict::TooHigh => value >= bound,
+                Verdict::TooLow => value <= bound,
+                _ => false
+            };
+            if out_of_bounds {
+                return Err(Refusal::OutOfBounds { verdict: attempt.verdict, bound: attempt.answer.clone() });
+            }
+        }
+
+        let wait_until = self.attempts(day, part).filter_map(|attempt| attempt.wait_until).max();
+        if let Some(wait_until) = wait_until.filter(|&wait_until| wait_until > now) {
+            return Err(Refusal::Cooldown { remaining: Duration::from_secs(wait_until - now) });
+        }
+        return Ok(());
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The history says the answer is not worth sending.
+    Refused(Refusal),
+    /// There is no session token to send the answer with.
+    NoSession,
+    /// The site answered with an error.
+    Http { status: u16, message: String },
+    /// The site could not be reached.
+    Transport(String),
+    /// The site answered with a page that does not say what became of the answer.
+    Unrecognised(String),
+    /// The history could not be read or written.
+    Io { path: PathBuf, error: io::Error }
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not sent: {refusal}"),
+            SubmitError::NoSession => write!(f, "no session token to send the answer with (set AOC_SESSION)"),
+            SubmitError::Http { status: 400 | 401 | 403, .. } => write!(f, "the session token was not accepted; it may have expired"),
+            SubmitError::Http { status, message } => write!(f, "could not send the answer: {status} {message}"),
+            SubmitError::Transport(message) => write!(f, "could not send the answer: {message}"),
+            SubmitError::Unrecognised(text) => write!(f, "the site's reply was not understood: {text}"),
+            SubmitError::Io { path, error } => write!(f, "{}: {error}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Sends answers to the site, keeping a history of them.
+#[derive(Debug, Clone)]
+pub struct Submitter {
+    /// The site to send answers to, without a trailing slash.
+    pub base_url: String,
+    /// The value of the `session` cookie of a logged in account.
+    pub session: Option<String>,
+    /// The JSON file the history is kept in.
+    pub history_path: PathBuf
+}
+
+impl Submitter {
+    pub fn history(&self) -> Result<History, SubmitError> {
+        return History::load(&self.history_path).map_err(|error| SubmitError::Io { path: self.history_path.clone(), error });
+    }
+
+    /// Sends `answer` to the part at `now`, unless the history says it is not worth it, and records what the site says.
+    pub fn submit(&self, day: u8, part: Part, answer: &str, now: SystemTime) -> Result<Response, SubmitError> {
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let mut history = self.history()?;
+        history.check(day, part, answer, now).map_err(SubmitError::Refused)?;
+        let Some(session) = &self.session else {
+            return Err(SubmitError::NoSession);
+        };
+
+        let url = format!("{}/day/{day}/answer", self.base_url);
+        let level = u8::from(part).to_string();
+        let result = ureq::post(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &level), ("answer", answer)]);
+        let page = match result {
+            Ok(page) => page.into_string().map_err(|error| SubmitError::Transport(error.to_string()))?,
+            Err(ureq::Error::Status(status, page)) => {
+                let message = page.into_string().unwrap_or_default().trim().to_string();
+                return Err(SubmitError::Http { status, message });
+            },
+            Err(error) => return Err(SubmitError::Transport(error.to_string()))
+        };
+        let Some(response) = parse_response(&page) else {
+            return Err(SubmitError::Unrecognised(article_text(&page)));
+        };
+
+        history.attempts.push(Attempt {
+            day,
+            part: u8::from(part),
+            answer: answer.to_string(),
+            verdict: response.verdict,
+            sent_at: now,
+            wait_until: response.wait.map(|wait| now + wait.as_secs())
+        });
+        history.save(&self.history_path).map_err(|error| SubmitError::Io { path: self.history_path.clone(), error })?;
+        return Ok(response);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use aoc_common::Part;
+
+    use crate::stand_in::StandIn;
+    use crate::submit::{parse_response, Refusal, Response, SubmitError, Submitter, Verdict};
+
+    fn page(message: &str) -> String {
+        return format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>");
+    }
+
+    fn at(seconds: u64) -> SystemTime {
+        return UNIX_EPOCH + Duration::from_secs(seconds);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations.");
+        assert_eq!(parse_response(&correct), Some(Response { verdict: Verdict::Correct, wait: None }));
+
+        let too_high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; \
+                             there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the \
+                             <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before \
+                             trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a>");
+        assert_eq!(parse_response(&too_high), Some(Response { verdict: Verdict::TooHigh, wait: Some(Duration::from_secs(60)) }));
+
+        let too_low = page("That's not the right answer; your answer is too low.  please wait 5 minutes before trying again.");
+        assert_eq!(parse_response(&too_low), Some(Response { verdict: Verdict::TooLow, wait: Some(Duration::from_secs(300)) }));
+
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(parse_response(&wrong), Some(Response { verdict: Verdict::Wrong, wait: Some(Duration::from_secs(60)) }));
+
+        let too_recent = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+                               You have 1m 23s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>");
+        assert_eq!(parse_response(&too_recent), Some(Response { verdict: Verdict::TooRecent, wait: Some(Duration::from_secs(83)) }));
+
+        let solved = page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a>");
+        assert_eq!(parse_response(&solved), Some(Response { verdict: Verdict::AlreadySolved, wait: None }));
+
+        assert_eq!(parse_response(&page("Please don't repeatedly request this endpoint before it unlocks!")), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = StandIn::start(|request| {
+            let message = match request.body.as_str() {
+                "level=1&answer=500" => "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+                "level=1&answer=100" => "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+                "level=1&answer=142" => "That's the right answer!",
+                _ => "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 30s left to wait."
+            };
+            return (200, page(message));
+        });
+        let history_path = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id())).join("submissions.json");
+        let _ = fs::remove_file(&history_path);
+        let submitter = Submitter { base_url: format!("{}/2023", server.url), session: Some("abc123".to_string()), history_path: history_path.clone() };
+
+        let response = submitter.submit(1, Part::One, "500", at(1000)).unwrap();
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        let requests = server.requests();
+        assert_eq!((requests[0].method.as_str(), requests[0].path.as_str()), ("POST", "/2023/day/1/answer"));
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("content-type"), Some("application/x-www-form-urlencoded"));
+
+        // None of these reach the site: one was already sent, one is higher than an answer that was too high, and the
+        // last is worth sending but the minute is not up yet.
+        let refusal = |answer: &str, now: u64| match submitter.submit(1, Part::One, answer, at(now)) {
+            Err(SubmitError::Refused(refusal)) => refusal,
+            result => panic!("expected a refusal, got {result:?}")
+        };
+        assert_eq!(refusal("500", 2000), Refusal::AlreadyTried { verdict: Verdict::TooHigh });
+        assert_eq!(refusal("501", 2000), Refusal::OutOfBounds { verdict: Verdict::TooHigh, bound: "500".to_string() });
+        assert_eq!(refusal("100", 1030), Refusal::Cooldown { remaining: Duration::from_secs(30) });
+        assert_eq!(server.requests().len(), 1);
+
+        assert_eq!(submitter.submit(1, Part::One, "100", at(1060)).unwrap().verdict, Verdict::TooLow);
+        assert_eq!(refusal("99", 2000), Refusal::OutOfBounds { verdict: Verdict::TooLow, bound: "100".to_string() });
+
+        // An answer the site would not judge yet is not counted as wrong, so it can be sent again once the wait is over.
+        let response = submitter.submit(1, Part::One, "150", at(1120)).unwrap();
+        assert_eq!(response, Response { verdict: Verdict::TooRecent, wait: Some(Duration::from_secs(30)) });
+        assert_eq!(refusal("142", 1140), Refusal::Cooldown { remaining: Duration::from_secs(10) });
+
+        assert_eq!(submitter.submit(1, Part::One, "142", at(1150)).unwrap().verdict, Verdict::Correct);
+        assert_eq!(refusal("142", 2000), Refusal::AlreadyCorrect { answer: "142".to_string() });
+        assert_eq!(server.requests().len(), 4);
+
+        // The history outlives the submitter, and the other part is unaffected by it.
+        let history = submitter.history().unwrap();
+        assert_eq!(history.attempts(1, Part::One).count(), 4);
+        assert_eq!(history.check(1, Part::Two, "500", 1150), Ok(()));
+        fs::remove_dir_all(history_path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_submit_errors() {
+        let server = StandIn::start(|_| (200, page("Please don't repeatedly request this endpoint before it unlocks!")));
+        let history_path = std::env::temp_dir().join(format!("aoc-submit-errors-{}", std::process::id())).join("submissions.json");
+
+        let mut submitter = Submitter { base_url: server.url.clone(), session: None, history_path: history_path.clone() };
+        assert!(matches!(submitter.submit(2, Part::Two, "2286", at(0)), Err(SubmitError::NoSession)));
+
+        submitter.session = Some("abc123".to_string());
+        let error = submitter.submit(2, Part::Two, "2286", at(0)).unwrap_err();
+        assert!(error.to_string().contains("request this endpoint"));
+        assert_eq!(server.requests().len(), 1);
+        assert!(!history_path.exists());
+    }
+}
@@ -0,0 +1,40 @@
+//--------------------------------------------------------------------------------
+// Common: Answers that some inputs do not have
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+/// What a part without an answer for its input shows instead of one.
+pub const NO_ANSWER: &str = "none";
+
+/// The answer to a part that some inputs have none to, like the cheapest path through a map with no path at all.
+/// It shows as [`NO_ANSWER`] then, so the part can report it instead of panicking or making up a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => write!(f, "{answer}"),
+            None => write!(f, "{NO_ANSWER}")
+        }
+    }
+}
+
+impl<T> From<Option<T>> for MaybeAnswer<T> {
+    fn from(value: Option<T>) -> Self {
+        return MaybeAnswer(value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::MaybeAnswer;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(MaybeAnswer(Some(94)).to_string(), "94");
+        assert_eq!(MaybeAnswer::<u16>(None).to_string(), "none");
+    }
+}
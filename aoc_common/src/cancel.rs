@@ -0,0 +1,83 @@
+//--------------------------------------------------------------------------------
+// Common: Telling a long-running solver to give up
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Why a solver gave up before it found the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// A token that a solver checks now and then inside its long loops, and stops once it is cancelled: either by hand,
+/// from any clone of the token, or because its time budget ran out.
+///
+/// Checking is cooperative. A solver that never checks runs to the end no matter what the token says.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>
+}
+
+impl Cancellation {
+    /// A token that is only ever cancelled by hand.
+    pub fn new() -> Cancellation {
+        return Cancellation::default();
+    }
+
+    /// A token that cancels itself `budget` from now, or never if that is further off than the clock can count.
+    pub fn with_budget(budget: Duration) -> Cancellation {
+        return Cancellation { cancelled: Arc::default(), deadline: Instant::now().checked_add(budget) };
+    }
+
+    /// Cancels this token and every clone of it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        return self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline);
+    }
+
+    /// `Err(Cancelled)` once the token is cancelled, so that a solver can give up with `?`.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            return Err(Cancelled);
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{Cancellation, Cancelled};
+
+    #[test]
+    fn test_cancel() {
+        let cancellation = Cancellation::new();
+        let clone = cancellation.clone();
+        assert_eq!(clone.check(), Ok(()));
+        cancellation.cancel();
+        assert_eq!(clone.check(), Err(Cancelled));
+    }
+
+    #[test]
+    fn test_budget() {
+        assert!(!Cancellation::with_budget(Duration::from_secs(60)).is_cancelled());
+        assert!(Cancellation::with_budget(Duration::ZERO).is_cancelled());
+        assert!(!Cancellation::with_budget(Duration::MAX).is_cancelled());
+    }
+}
@@ -0,0 +1,127 @@
+//--------------------------------------------------------------------------------
+// Common: Pieces shared by every day and the runner
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+mod answer;
+mod cancel;
+mod params;
+mod parse;
+
+use std::fmt::Display;
+
+pub use answer::{MaybeAnswer, NO_ANSWER};
+pub use cancel::{Cancellation, Cancelled};
+pub use params::Params;
+pub use parse::{lines, parse_file, parse_reader, read_input, Line, ParseError};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err("A puzzle only has parts 1 and 2.")
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+/// A day's solution, split the same way the puzzle is: read the input once, then answer each part from it.
+pub trait Solver {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+    /// The puzzle's title, as written in the header of the day's source.
+    const TITLE: &'static str;
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    fn solve(input: &Self::Input, part: Part) -> String {
+        match part {
+            Part::One => Self::part_one(input).to_string(),
+            Part::Two => Self::part_two(input).to_string(),
+        }
+    }
+
+    /// The names of the [`Params`] that [`Solver::part_one_with`] and [`Solver::part_two_with`] read. Most days have
+    /// none, since everything they need is in the input.
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// [`Solver::part_one`], with the puzzle's constants overridden by `params`. Solvers with [`Solver::PARAMS`]
+    /// override this, and answer [`Solver::part_one`] with no overrides.
+    fn part_one_with(input: &Self::Input, _params: &Params) -> Self::PartOne {
+        return Self::part_one(input);
+    }
+
+    /// [`Solver::part_two`], with the puzzle's constants overridden by `params`, like [`Solver::part_one_with`].
+    fn part_two_with(input: &Self::Input, _params: &Params) -> Self::PartTwo {
+        return Self::part_two(input);
+    }
+
+    /// [`Solver::part_one_with`], but giving up once `cancellation` is cancelled. Solvers whose loops can run for long
+    /// override this to check it as they go; the rest only check it before they start.
+    fn part_one_cancellable(input: &Self::Input, params: &Params, cancellation: &Cancellation) -> Result<Self::PartOne, Cancelled> {
+        cancellation.check()?;
+        return Ok(Self::part_one_with(input, params));
+    }
+
+    /// [`Solver::part_two_with`], but giving up once `cancellation` is cancelled, like [`Solver::part_one_cancellable`].
+    fn part_two_cancellable(input: &Self::Input, params: &Params, cancellation: &Cancellation) -> Result<Self::PartTwo, Cancelled> {
+        cancellation.check()?;
+        return Ok(Self::part_two_with(input, params));
+    }
+
+    fn solve_cancellable(input: &Self::Input, part: Part, params: &Params, cancellation: &Cancellation) -> Result<String, Cancelled> {
+        match part {
+            Part::One => return Self::part_one_cancellable(input, params, cancellation).map(|answer| answer.to_string()),
+            Part::Two => return Self::part_two_cancellable(input, params, cancellation).map(|answer| answer.to_string())
+        }
+    }
+}
+
+/// Reads `file_name` and prints both answers. Used by each day's `main`.
+pub fn solve_file<S: Solver>(file_name: &str) {
+    let input = match parse_file(file_name, S::parse) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+    };
+
+    for part in Part::BOTH {
+        println!("Part {}: {}", part, S::solve(&input, part));
+    }
+}
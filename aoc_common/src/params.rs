@@ -0,0 +1,50 @@
+//--------------------------------------------------------------------------------
+// Common: Puzzle constants that can be overridden
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+/// Overrides for the constants a puzzle gives in its text rather than its input, like the cubes in the bag on day 02
+/// or how much the universe expands on day 11, by name. A solver lists the names it reads in [`crate::Solver::PARAMS`]
+/// and falls back on the puzzle's own value for any name that is not set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, u64>
+}
+
+impl Params {
+    /// No overrides, so every solver uses the puzzle's own values.
+    pub const fn new() -> Params {
+        return Params { values: BTreeMap::new() };
+    }
+
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// The value set for `name`, or else `default`.
+    pub fn get_or(&self, name: &str, default: u64) -> u64 {
+        return self.values.get(name).copied().unwrap_or(default);
+    }
+
+    /// Every name that has a value, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        return self.values.keys().map(|name| name.as_str());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Params;
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::new();
+        assert_eq!(params.get_or("expansion", 1_000_000), 1_000_000);
+        params.set("expansion", 10);
+        params.set("bag.red", 12);
+        assert_eq!(params.get_or("expansion", 1_000_000), 10);
+        assert_eq!(params.names().collect::<Vec<&str>>(), vec!["bag.red", "expansion"]);
+    }
+}
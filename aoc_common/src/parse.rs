@@ -0,0 +1,187 @@
+//--------------------------------------------------------------------------------
+// Common: Reading puzzle inputs and reporting where they are wrong
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Why a puzzle input could not be read, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The file the input came from, if it came from one.
+    pub file: Option<PathBuf>,
+    /// The line the problem is on, counting from 1. It is 0 if the problem is not on any line, like a missing file.
+    pub line: usize,
+    /// The column the problem starts at, counting bytes from 1. It is 0 if the problem is not at any column.
+    pub column: usize,
+    /// What is wrong, written for a human.
+    pub message: String
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        return ParseError { file: None, line, column, message: message.into() };
+    }
+
+    /// An error for something missing after the last line of `input`.
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> ParseError {
+        return ParseError::new(input.lines().count() + 1, 1, message);
+    }
+
+    /// Records which file the input came from, unless it is already known.
+    pub fn in_file(mut self, file: impl Into<PathBuf>) -> ParseError {
+        if self.file.is_none() {
+            self.file = Some(file.into());
+        }
+        return self;
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}", file.display())?,
+            None => write!(f, "input")?
+        }
+        if self.line > 0 {
+            write!(f, ":{}", self.line)?;
+            if self.column > 0 {
+                write!(f, ":{}", self.column)?;
+            }
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of a puzzle input that knows its number, so that it can point at the part of itself that is wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// The line's number, counting from 1.
+    pub number: usize,
+    pub text: &'a str
+}
+
+impl<'a> Line<'a> {
+    /// The column `token` starts at, if it was sliced out of this line. Otherwise, the start of the line.
+    pub fn column_of(&self, token: &str) -> usize {
+        let line_start = self.text.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+        if (line_start..=line_start + self.text.len()).contains(&token_start) {
+            return token_start - line_start + 1;
+        }
+        return 1;
+    }
+
+    pub fn error_at(&self, column: usize, message: impl Into<String>) -> ParseError {
+        return ParseError::new(self.number, column, message);
+    }
+
+    /// An error pointing at `token`, which should be a slice of this line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        return self.error_at(self.column_of(token), message);
+    }
+
+    /// An error for something missing at the end of this line.
+    pub fn end_error(&self, message: impl Into<String>) -> ParseError {
+        return self.error_at(self.text.len() + 1, message);
+    }
+
+    /// Parses `token`, which should be a slice of this line.
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError> where T: FromStr, T::Err: Display {
+        return token.parse::<T>()
+                    .map_err(|error| self.error(token, format!("could not read `{token}`: {error}")));
+    }
+
+    /// Splits `text`, which should be a slice of this line, around the first `delimiter`.
+    pub fn split_once(&self, text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        return text.split_once(delimiter)
+                    .ok_or_else(|| self.error(text, format!("expected `{delimiter}` in `{text}`")));
+    }
+}
+
+/// Numbers the lines of `input`.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    return input.lines().enumerate().map(|(i, text)| Line { number: i + 1, text });
+}
+
+/// Reads the whole of `file_name`.
+pub fn read_input(file_name: impl AsRef<Path>) -> Result<String, ParseError> {
+    let file_name = file_name.as_ref();
+    return fs::read_to_string(file_name)
+                .map_err(|error| ParseError::new(0, 0, format!("could not read the file: {error}")).in_file(file_name));
+}
+
+/// Reads everything `reader` has to give and parses it.
+pub fn parse_reader<T>(mut reader: impl Read, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> Result<T, ParseError> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)
+        .map_err(|error| ParseError::new(0, 0, format!("could not read the input: {error}")))?;
+    return parse(&contents);
+}
+
+/// Reads `file_name` and parses it, noting the file in any error.
+pub fn parse_file<T>(file_name: impl AsRef<Path>, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> Result<T, ParseError> {
+    let file_name = file_name.as_ref();
+    let file = File::open(file_name)
+                .map_err(|error| ParseError::new(0, 0, format!("could not read the file: {error}")).in_file(file_name))?;
+    return parse_reader(file, parse).map_err(|error| error.in_file(file_name));
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::parse::{lines, parse_file, parse_reader, Line, ParseError};
+
+    #[test]
+    fn test_column_of() {
+        let line = Line { number: 3, text: "Time:      7  15   30" };
+        let mut tokens = line.text.split_whitespace();
+        tokens.next();
+
+        assert_eq!(line.column_of(tokens.next().unwrap()), 12);
+        assert_eq!(line.column_of(&String::from("unrelated")), 1);
+    }
+
+    #[test]
+    fn test_parse() {
+        let line = lines("467..114..\nSeeds: 79 x4").nth(1).unwrap();
+        let tokens: Vec<&str> = line.text.split_whitespace().collect();
+
+        assert_eq!(line.parse::<u64>(tokens[1]), Ok(79));
+        let error = line.parse::<u64>(tokens[2]).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(error.to_string(), "input:2:11: could not read `x4`: invalid digit found in string");
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(4, 2, "unexpected character `?`").in_file("day16/input.txt").in_file("ignored.txt");
+        assert_eq!(error.to_string(), "day16/input.txt:4:2: unexpected character `?`");
+        assert_eq!(ParseError::end_of_input("a\nb\n", "expected a line").line, 3);
+    }
+
+    #[test]
+    fn test_parse_file() {
+        let error = parse_file("does/not/exist.txt", |input| Ok(input.len())).unwrap_err();
+        assert_eq!(error.file, Some(PathBuf::from("does/not/exist.txt")));
+        assert_eq!(error.line, 0);
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let input: &[u8] = b"12\n34\n";
+        assert_eq!(parse_reader(input, |input| Ok(input.lines().count())), Ok(2));
+
+        let input: &[u8] = b"12\n\xff\n";
+        let error = parse_reader(input, |input| Ok(input.len())).unwrap_err();
+        assert_eq!((error.file, error.line), (None, 0));
+    }
+}
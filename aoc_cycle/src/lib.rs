@@ -0,0 +1,151 @@
+//--------------------------------------------------------------------------------
+// Cycle: Finding where an iterated state starts repeating itself
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Finds the loop that a state falls into when the same step is applied to it over and over, so that the state after
+//! a billion steps can be read off without taking them all.
+//!
+//! Both finders only end if the state does repeat eventually, which is always the case when there are finitely many states.
+
+use std::{collections::HashMap, convert::Infallible, hash::Hash};
+
+/// The shape of the sequence of states: `tail` steps lead up to a loop of `period` steps that then repeats forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step whose state is seen again later.
+    pub tail: usize,
+    /// How many steps it takes for the states in the loop to come back.
+    pub period: usize
+}
+
+impl Cycle {
+    /// The first step that reaches the same state as step `n`.
+    pub fn reduce(&self, n: u64) -> usize {
+        if n < self.tail as u64 {
+            return n as usize;
+        }
+        return self.tail + ((n - self.tail as u64) % self.period as u64) as usize;
+    }
+}
+
+/// Finds the cycle by remembering every state seen so far.
+/// Also returns the states from step 0 up to the end of the first loop, which [`Cycle::reduce`] indexes into.
+pub fn find_cycle<S>(start: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) where S: Clone + Hash + Eq {
+    let Ok(found) = try_find_cycle(start, |state| Ok::<S, Infallible>(step(state)));
+    return found;
+}
+
+/// [`find_cycle`] with a step that can fail, for instance by giving up when it is taking too long.
+/// Stops at the first error and returns it.
+pub fn try_find_cycle<S, E>(start: S, mut step: impl FnMut(&S) -> Result<S, E>) -> Result<(Cycle, Vec<S>), E> where S: Clone + Hash + Eq {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut current = start;
+    loop {
+        if let Some(&tail) = seen.get(&current) {
+            return Ok((Cycle { tail, period: states.len() - tail }, states));
+        }
+        let next = step(&current)?;
+        seen.insert(current.clone(), states.len());
+        states.push(current);
+        current = next;
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only ever keeps a couple of states around and does not need to hash them.
+/// It takes more steps than [`find_cycle`], so it suits states that are cheap to step but expensive to store.
+pub fn brent<S>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle where S: Clone + Eq {
+    // Find the period: a tortoise waits at each power of two for the hare to come back around to it.
+    let mut power: usize = 1;
+    let mut period: usize = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Find the tail: with the hare `period` steps ahead, both meet at the first state of the loop.
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut tail: usize = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    return Cycle { tail, period };
+}
+
+/// The state after `n` steps from `start`, taking at most as many steps as it takes to find the cycle.
+pub fn state_at<S>(start: S, step: impl FnMut(&S) -> S, n: u64) -> S where S: Clone + Hash + Eq {
+    let (cycle, mut states) = find_cycle(start, step);
+    return states.swap_remove(cycle.reduce(n));
+}
+
+/// [`state_at`] with a step that can fail. Stops at the first error and returns it.
+pub fn try_state_at<S, E>(start: S, step: impl FnMut(&S) -> Result<S, E>, n: u64) -> Result<S, E> where S: Clone + Hash + Eq {
+    let (cycle, mut states) = try_find_cycle(start, step)?;
+    return Ok(states.swap_remove(cycle.reduce(n)));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{brent, find_cycle, state_at, try_state_at, Cycle};
+
+    // 2, 4, 16, 256, 536, 296, ..., 56, 136, 496 and back to 16: a tail of 2 steps and a loop of 20.
+    fn square_mod(value: &u64) -> u64 {
+        return value * value % 1000;
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let (cycle, states) = find_cycle(2, square_mod);
+        assert_eq!(cycle, Cycle { tail: 2, period: 20 });
+        assert_eq!(states.len(), 22);
+        assert_eq!(states[cycle.reduce(22)], 16);
+        assert_eq!(states[cycle.reduce(1)], 4);
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(&2, square_mod), Cycle { tail: 2, period: 20 });
+        assert_eq!(brent(&0, square_mod), Cycle { tail: 0, period: 1 });
+        assert_eq!(brent(&3_u8, |value| (value + 1) % 5), Cycle { tail: 0, period: 5 });
+    }
+
+    #[test]
+    fn test_state_at() {
+        let mut state = 2;
+        for n in 0..100 {
+            assert_eq!(state_at(2, square_mod, n), state);
+            state = square_mod(&state);
+        }
+        assert_eq!(state_at(2, square_mod, 1_000_000_002), 16);
+    }
+
+    #[test]
+    fn test_try_state_at() {
+        assert_eq!(try_state_at(2, |value| Ok::<u64, ()>(square_mod(value)), 1_000_000_002), Ok(16));
+
+        let mut steps = 0;
+        let gives_up = try_state_at(2, |value| {
+            steps += 1;
+            if steps > 5 {
+                return Err("too many steps");
+            }
+            return Ok(square_mod(value));
+        }, 1_000_000_002);
+        assert_eq!(gives_up, Err("too many steps"));
+    }
+}
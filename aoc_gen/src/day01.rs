@@ -0,0 +1,34 @@
+//--------------------------------------------------------------------------------
+// Day 01: Random calibration documents
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Size;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// `count` lines of about `width` letters each, with digits and spelled-out digits mixed in. Every line has at least one
+/// digit, as part 1 needs.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let mut input = String::new();
+    for _ in 0..size.count.max(1) {
+        let mut line = String::new();
+        while line.len() < size.width.max(1) {
+            match rng.gen_range(0..6) {
+                0 => line.push(char::from_digit(rng.gen_range(1..=9), 10).unwrap()),
+                1 => line.push_str(WORDS.choose(rng).unwrap()),
+                _ => line.push(rng.gen_range('a'..='z'))
+            }
+        }
+        if !line.chars().any(|character| character.is_ascii_digit()) {
+            let position = rng.gen_range(0..=line.len());
+            line.insert(position, char::from_digit(rng.gen_range(1..=9), 10).unwrap());
+        }
+        input.push_str(&line);
+        input.push('\n');
+    }
+    return input;
+}
@@ -0,0 +1,29 @@
+//--------------------------------------------------------------------------------
+// Day 02: Random games of cubes
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Size;
+
+/// `count` games of up to `width` sets each, where a set shows between 1 and 20 cubes of each of up to three colours.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let mut input = String::new();
+    for game in 1..=size.count.max(1) {
+        let sets: Vec<String> = (0..rng.gen_range(1..=size.width.max(1)))
+            .map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                colours.shuffle(rng);
+                let shown = rng.gen_range(1..=3);
+                return colours[..shown].iter()
+                    .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+            })
+            .collect();
+        input.push_str(&format!("Game {game}: {}\n", sets.join("; ")));
+    }
+    return input;
+}
@@ -0,0 +1,37 @@
+//--------------------------------------------------------------------------------
+// Day 03: Random engine schematics
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{grid_to_string, Size};
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'];
+
+/// A `width` by `height` schematic of numbers of up to three digits, with symbols and gears scattered between them.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let width = size.width.max(1);
+    let schematic: Vec<Vec<char>> = (0..size.height.max(1))
+        .map(|_| {
+            let mut row: Vec<char> = Vec::new();
+            while row.len() < width {
+                match rng.gen_range(0..10) {
+                    0..=2 => {
+                        let number: u32 = rng.gen_range(1..1000);
+                        row.extend(number.to_string().chars().take(width - row.len()));
+                        if row.len() < width {
+                            row.push('.');
+                        }
+                    },
+                    3 => row.push(*SYMBOLS.choose(rng).unwrap()),
+                    4 => row.push('*'),
+                    _ => row.push('.')
+                }
+            }
+            return row;
+        })
+        .collect();
+    return grid_to_string(&schematic);
+}
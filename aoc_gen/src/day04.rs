@@ -0,0 +1,33 @@
+//--------------------------------------------------------------------------------
+// Day 04: Random piles of scratchcards
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Size;
+
+/// `count` cards of `width` winning numbers and `height` owned numbers. A card never wins copies of cards past the
+/// end of the table.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let count = size.count.max(1);
+    let winning_count = size.width.clamp(1, 30);
+    let owned_count = size.height.clamp(1, 60);
+
+    let mut input = String::new();
+    for card in 1..=count {
+        let mut numbers: Vec<u32> = (1..100).collect();
+        numbers.shuffle(rng);
+        let matches = rng.gen_range(0..=winning_count.min(owned_count).min(count - card));
+
+        let winning = &numbers[..winning_count];
+        let mut owned: Vec<u32> = winning[..matches].to_vec();
+        owned.extend(&numbers[winning_count..winning_count + owned_count - matches]);
+        owned.shuffle(rng);
+
+        let format_numbers = |numbers: &[u32]| numbers.iter().map(|number| format!("{number:>2}")).collect::<Vec<String>>().join(" ");
+        input.push_str(&format!("Card {card:>3}: {} | {}\n", format_numbers(winning), format_numbers(&owned)));
+    }
+    return input;
+}
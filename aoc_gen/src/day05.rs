@@ -0,0 +1,37 @@
+//--------------------------------------------------------------------------------
+// Day 05: Random almanacs
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::Size;
+
+const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+/// The highest number in an almanac, as in the real ones.
+const SPAN: u64 = 4_000_000_000;
+
+/// An almanac of `count` seed ranges and `height` maps, each made of `width` ranges that split the numbers between
+/// them and move every piece somewhere else.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let seeds: Vec<String> = (0..size.count.max(1))
+        .map(|_| format!("{} {}", rng.gen_range(0..SPAN), rng.gen_range(1..SPAN / 20)))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for stage in 0..size.height.max(1) {
+        let category = |stage: usize| CATEGORIES.get(stage).map(|category| category.to_string()).unwrap_or(format!("stage{stage}"));
+        input.push_str(&format!("\n{}-to-{} map:\n", category(stage), category(stage + 1)));
+
+        let mut cuts: Vec<u64> = (1..size.width.max(1)).map(|_| rng.gen_range(1..SPAN)).collect();
+        cuts.extend([0, SPAN]);
+        cuts.sort();
+        cuts.dedup();
+        for piece in cuts.windows(2) {
+            let length = piece[1] - piece[0];
+            input.push_str(&format!("{} {} {length}\n", rng.gen_range(0..=SPAN - length), piece[0]));
+        }
+    }
+    return input;
+}
@@ -0,0 +1,38 @@
+//--------------------------------------------------------------------------------
+// Day 06: Random race records
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::Size;
+
+/// Part 2 reads all the times as one number, and all the distances as another, so they have to fit in 64 bits.
+const MAX_DIGITS: usize = 18;
+
+/// `count` races of up to `width` milliseconds, each with a record that can be beaten. Races past the ones whose digits
+/// still fit in a 64 bit number are left out.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let mut races: Vec<(u64, u64)> = Vec::new();
+    let (mut time_digits, mut distance_digits) = (0, 0);
+    for _ in 0..size.count.max(1) {
+        let time: u64 = rng.gen_range(2..=size.width.max(2) as u64);
+        let best = (time / 2) * (time - time / 2);
+        let distance = rng.gen_range(0..best);
+        time_digits += time.to_string().len();
+        distance_digits += distance.to_string().len();
+        if !races.is_empty() && (time_digits > MAX_DIGITS || distance_digits > MAX_DIGITS) {
+            break;
+        }
+        races.push((time, distance));
+    }
+
+    let mut times = String::from("Time:    ");
+    let mut distances = String::from("Distance:");
+    for (time, distance) in races {
+        let column_width = distance.to_string().len().max(time.to_string().len()) + 2;
+        times.push_str(&format!("{time:>column_width$}"));
+        distances.push_str(&format!("{distance:>column_width$}"));
+    }
+    return format!("{times}\n{distances}\n");
+}
@@ -0,0 +1,21 @@
+//--------------------------------------------------------------------------------
+// Day 07: Random hands of Camel Cards
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Size;
+
+const LABELS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+/// `count` hands of five cards, each with a bid of up to 1000.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let mut input = String::new();
+    for _ in 0..size.count.max(1) {
+        let cards: String = (0..5).map(|_| *LABELS.choose(rng).unwrap()).collect();
+        input.push_str(&format!("{cards} {}\n", rng.gen_range(1..=1000)));
+    }
+    return input;
+}
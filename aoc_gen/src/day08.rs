@@ -0,0 +1,46 @@
+//--------------------------------------------------------------------------------
+// Day 08: Random networks of nodes
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{unique_names, Size};
+
+const LETTERS: [char; 26] = ['A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+                             'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z'];
+
+/// `width` instructions and a network that `count` ghosts walk, the first of them from `AAA` to `ZZZ`. Each ghost's
+/// path leads from its start into a loop of up to `height` nodes that ends in its `Z` node, so every ghost is on a `Z`
+/// node once the number of steps is a multiple of the length of its loop.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let ghosts = size.count.clamp(1, 26 * 26 - 1);
+    let instructions: String = (0..size.width.max(1)).map(|_| *['L', 'R'].choose(rng).unwrap()).collect();
+
+    // The first ghost starts on `AAA`, and the others on two letters followed by `A`, which no two ghosts share.
+    let mut prefixes = unique_names(rng, ghosts - 1, 2, &LETTERS, &["AA", "ZZ"]);
+    prefixes.insert(0, "AA".to_string());
+    let inner_letters: Vec<char> = LETTERS.iter().copied().filter(|letter| !matches!(letter, 'A' | 'Z')).collect();
+    let loop_lengths: Vec<usize> = (0..ghosts).map(|_| rng.gen_range(1..=size.height.max(1))).collect();
+    let mut inner_names = unique_names(rng, loop_lengths.iter().map(|length| length - 1).sum(), 3, &inner_letters, &[]).into_iter();
+
+    let mut nodes: Vec<(String, String)> = Vec::new();
+    for (ghost, prefix) in prefixes.iter().enumerate() {
+        let end = if ghost == 0 { "ZZZ".to_string() } else { format!("{}Z", prefix) };
+        let mut path: Vec<String> = inner_names.by_ref().take(loop_lengths[ghost] - 1).collect();
+        path.push(end);
+
+        nodes.push((format!("{prefix}A"), path[0].clone()));
+        for (i, node) in path.iter().enumerate() {
+            nodes.push((node.clone(), path[(i + 1) % path.len()].clone()));
+        }
+    }
+    nodes.shuffle(rng);
+
+    let mut input = format!("{instructions}\n\n");
+    for (node, next) in nodes {
+        input.push_str(&format!("{node} = ({next}, {next})\n"));
+    }
+    return input;
+}
@@ -0,0 +1,25 @@
+//--------------------------------------------------------------------------------
+// Day 09: Random OASIS reports
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::Size;
+
+/// `count` histories of `width` values each, read off polynomials of degree up to `height` (at most 6) with small
+/// coefficients, so their differences always end in zeros.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let length = size.width.max(2) as i64;
+    let mut input = String::new();
+    for _ in 0..size.count.max(1) {
+        let degree = rng.gen_range(0..=size.height.min(6));
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.gen_range(-3..=3)).collect();
+        let values: Vec<String> = (0..length)
+            .map(|x| coefficients.iter().rev().fold(0, |value, coefficient| value * x + coefficient).to_string())
+            .collect();
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+    return input;
+}
@@ -0,0 +1,82 @@
+//--------------------------------------------------------------------------------
+// Day 10: Random fields of pipes
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::{grid, grid_to_string, Size};
+
+/// The pipe joining the cells either side of it.
+fn pipe(from: (usize, usize), at: (usize, usize), to: (usize, usize)) -> char {
+    let side = |(x, y): (usize, usize)| match (x.cmp(&at.0), y.cmp(&at.1)) {
+        (_, std::cmp::Ordering::Less) => 'N',
+        (_, std::cmp::Ordering::Greater) => 'S',
+        (std::cmp::Ordering::Less, _) => 'W',
+        _ => 'E'
+    };
+    match (side(from), side(to)) {
+        ('N', 'S') | ('S', 'N') => return '|',
+        ('E', 'W') | ('W', 'E') => return '-',
+        ('N', 'E') | ('E', 'N') => return 'L',
+        ('N', 'W') | ('W', 'N') => return 'J',
+        ('S', 'W') | ('W', 'S') => return '7',
+        _ => return 'F'
+    }
+}
+
+/// Extends the path in `cells` to `(x, y)`, first along the row and then along the column.
+fn walk_to(cells: &mut Vec<(usize, usize)>, (x, y): (usize, usize)) {
+    let &(mut at_x, mut at_y) = cells.last().unwrap();
+    while (at_x, at_y) != (x, y) {
+        if at_x != x {
+            at_x = if at_x < x { at_x + 1 } else { at_x - 1 };
+        } else {
+            at_y = if at_y < y { at_y + 1 } else { at_y - 1 };
+        }
+        cells.push((at_x, at_y));
+    }
+}
+
+/// A `width` by `height` field with a loop of pipe through `S` and stray pipes around it.
+///
+/// Every column of the loop has a top above the middle row and a bottom below it. The loop runs along the tops from
+/// left to right, climbing or dropping between columns, down the last column, back along the bottoms and up the first
+/// column, so it never crosses itself.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let (width, height) = (size.width.max(2), size.height.max(2));
+    let middle = height / 2;
+    let mut tops: Vec<usize> = (0..width).map(|_| rng.gen_range(0..middle)).collect();
+    let mut bottoms: Vec<usize> = (0..width).map(|_| rng.gen_range(middle..height)).collect();
+    // The first and last columns are straight, or the loop would double back on itself in them.
+    tops[width - 1] = tops[width - 2];
+    bottoms[0] = bottoms[1];
+
+    let mut cells: Vec<(usize, usize)> = vec![(0, tops[0])];
+    for x in 1..width {
+        walk_to(&mut cells, (x, tops[x - 1]));
+        walk_to(&mut cells, (x, tops[x]));
+    }
+    walk_to(&mut cells, (width - 1, bottoms[width - 1]));
+    for x in (0..width - 1).rev() {
+        walk_to(&mut cells, (x, bottoms[x + 1]));
+        walk_to(&mut cells, (x, bottoms[x]));
+    }
+    walk_to(&mut cells, (0, tops[0]));
+    cells.pop();
+
+    let mut field = grid(rng, width, height, &[('.', 4), ('|', 1), ('-', 1), ('L', 1), ('J', 1), ('7', 1), ('F', 1)]);
+    for (i, &(x, y)) in cells.iter().enumerate() {
+        field[y][x] = pipe(cells[(i + cells.len() - 1) % cells.len()], (x, y), cells[(i + 1) % cells.len()]);
+    }
+
+    // Stray pipes next to `S` must not look like they connect to it.
+    let (start_x, start_y) = cells[rng.gen_range(0..cells.len())];
+    for (x, y) in [(start_x.wrapping_sub(1), start_y), (start_x + 1, start_y), (start_x, start_y.wrapping_sub(1)), (start_x, start_y + 1)] {
+        if x < width && y < height && !cells.contains(&(x, y)) {
+            field[y][x] = '.';
+        }
+    }
+    field[start_y][start_x] = 'S';
+    return grid_to_string(&field);
+}
@@ -0,0 +1,13 @@
+//--------------------------------------------------------------------------------
+// Day 11: Random images of galaxies
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::{grid, grid_to_string, Size};
+
+/// A `width` by `height` image where about one tile in ten is a galaxy.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    return grid_to_string(&grid(rng, size.width.max(1), size.height.max(1), &[('.', 9), ('#', 1)]));
+}
@@ -0,0 +1,29 @@
+//--------------------------------------------------------------------------------
+// Day 12: Random condition records
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::Size;
+
+/// `count` records of `width` springs each. Each record is read off a row of springs that fits its groups, with about
+/// half of them hidden behind `?`, so it always has at least one arrangement.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let width = size.width.max(1);
+    let mut input = String::new();
+    for _ in 0..size.count.max(1) {
+        let mut springs: Vec<char> = (0..width).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }).collect();
+        if !springs.contains(&'#') {
+            springs[rng.gen_range(0..width)] = '#';
+        }
+
+        let groups: Vec<String> = springs.split(|spring| *spring == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let record: String = springs.iter().map(|&spring| if rng.gen_bool(0.5) { '?' } else { spring }).collect();
+        input.push_str(&format!("{record} {}\n", groups.join(",")));
+    }
+    return input;
+}
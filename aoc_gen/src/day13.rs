@@ -0,0 +1,80 @@
+//--------------------------------------------------------------------------------
+// Day 13: Random patterns of ash and rocks
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::{grid, grid_to_string, Size};
+
+fn transpose(pattern: &Vec<Vec<char>>) -> Vec<Vec<char>> {
+    return (0..pattern[0].len()).map(|x| pattern.iter().map(|row| row[x]).collect()).collect();
+}
+
+/// For every line between two rows, how many tiles differ from their reflection across it.
+fn row_differences(pattern: &Vec<Vec<char>>) -> Vec<usize> {
+    return (1..pattern.len())
+        .map(|line| {
+            return (0..line.min(pattern.len() - line))
+                .map(|k| pattern[line - 1 - k].iter().zip(&pattern[line + k]).filter(|(above, below)| above != below).count())
+                .sum();
+        })
+        .collect();
+}
+
+/// A pattern with exactly one line of reflection, and exactly one other line that is a reflection but for one tile.
+///
+/// The pattern is mirrored across a line between rows, and across a line between columns. The rows nearer the edge
+/// than the row line's reflection reaches are then smudged in one tile, which keeps the row line but spoils the
+/// column line.
+fn pattern(rng: &mut impl Rng, width: usize, height: usize) -> Vec<Vec<char>> {
+    loop {
+        let mut pattern = grid(rng, width, height, &[('.', 1), ('#', 1)]);
+        let row_line = loop {
+            let line = rng.gen_range(1..height);
+            if 2 * line != height {
+                break line;
+            }
+        };
+        let column_line = rng.gen_range(1..width);
+        let row_reach = row_line.min(height - row_line);
+        let column_reach = column_line.min(width - column_line);
+
+        for row in pattern.iter_mut() {
+            for k in 0..column_reach {
+                row[column_line + k] = row[column_line - 1 - k];
+            }
+        }
+        for k in 0..row_reach {
+            pattern[row_line + k] = pattern[row_line - 1 - k].clone();
+        }
+
+        let unmirrored_rows: Vec<usize> = (0..height).filter(|&row| row + row_reach < row_line || row >= row_line + row_reach).collect();
+        let row = unmirrored_rows[rng.gen_range(0..unmirrored_rows.len())];
+        let column = rng.gen_range(column_line - column_reach..column_line + column_reach);
+        pattern[row][column] = if pattern[row][column] == '#' { '.' } else { '#' };
+
+        // Random tiles can line up into more reflections than the two that were made. Such patterns are thrown away.
+        let mut differences = row_differences(&pattern);
+        differences.extend(row_differences(&transpose(&pattern)));
+        if differences.iter().filter(|&&difference| difference == 0).count() == 1 && differences.iter().filter(|&&difference| difference == 1).count() == 1 {
+            return pattern;
+        }
+    }
+}
+
+/// `count` patterns of up to `width` by `height` tiles (at least 3 by 3), each with exactly one line of reflection
+/// and exactly one more once its smudge is cleaned.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let patterns: Vec<String> = (0..size.count.max(1))
+        .map(|_| {
+            let width = rng.gen_range(3..=size.width.max(3));
+            let height = rng.gen_range(3..=size.height.max(3));
+            if rng.gen_bool(0.5) {
+                return grid_to_string(&transpose(&pattern(rng, height, width)));
+            }
+            return grid_to_string(&pattern(rng, width, height));
+        })
+        .collect();
+    return patterns.join("\n");
+}
@@ -0,0 +1,13 @@
+//--------------------------------------------------------------------------------
+// Day 14: Random platforms of rocks
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::{grid, grid_to_string, Size};
+
+/// A `width` by `height` platform with about a quarter of its tiles rounded rocks and a sixth cube-shaped rocks.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    return grid_to_string(&grid(rng, size.width.max(1), size.height.max(1), &[('.', 7), ('O', 3), ('#', 2)]));
+}
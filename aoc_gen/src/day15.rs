@@ -0,0 +1,35 @@
+//--------------------------------------------------------------------------------
+// Day 15: Random initialization sequences
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{unique_names, Size};
+
+const LETTERS: [char; 26] = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+                             'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
+
+/// `count` steps that put lenses in and take them out, with labels of up to `width` letters. Labels are drawn from a
+/// few per step so the same lens comes up more than once.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let count = size.count.max(1);
+    let labels: Vec<String> = (0..count.div_ceil(3))
+        .map(|_| {
+            let length = rng.gen_range(1..=size.width.clamp(1, 8));
+            return unique_names(rng, 1, length, &LETTERS, &[]).remove(0);
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..count)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.3) {
+                return format!("{label}-");
+            }
+            return format!("{label}={}", rng.gen_range(1..=9));
+        })
+        .collect();
+    return format!("{}\n", steps.join(","));
+}
@@ -0,0 +1,14 @@
+//--------------------------------------------------------------------------------
+// Day 16: Random contraptions
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::{grid, grid_to_string, Size};
+
+/// A `width` by `height` contraption with about one tile in six a mirror or a splitter.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let tiles = [('.', 20), ('/', 1), ('\\', 1), ('|', 1), ('-', 1)];
+    return grid_to_string(&grid(rng, size.width.max(1), size.height.max(1), &tiles));
+}
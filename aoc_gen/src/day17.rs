@@ -0,0 +1,17 @@
+//--------------------------------------------------------------------------------
+// Day 17: Random city maps
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::{grid_to_string, Size};
+
+/// A `width` by `height` city where every block loses between 1 and 9 heat. Below 5 by 5, the ultra crucible of part
+/// 2, which moves at least four blocks at a time, cannot reach the far corner, and that is left in to be tested.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let city: Vec<Vec<char>> = (0..size.height.max(1))
+        .map(|_| (0..size.width.max(1)).map(|_| char::from_digit(rng.gen_range(1..=9), 10).unwrap()).collect())
+        .collect();
+    return grid_to_string(&city);
+}
@@ -0,0 +1,57 @@
+//--------------------------------------------------------------------------------
+// Day 18: Random dig plans
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::Rng;
+
+use crate::Size;
+
+/// The largest distance the five hexadecimal digits of a colour can hold.
+const MAX_CORRECTED_DISTANCE: u64 = 0xFFFFF;
+
+/// A plan for a trench around `count` columns of up to `width` metres each, reaching up to `height` metres above and
+/// below the start. The colours hold the same trench scaled up, so both parts dig a loop that never crosses itself.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let columns = size.count.max(1);
+    let widths: Vec<u64> = (0..columns).map(|_| rng.gen_range(1..=size.width.max(1) as u64)).collect();
+    let tops: Vec<i64> = (0..columns).map(|_| -rng.gen_range(1..=size.height.max(1) as i64)).collect();
+    let bottoms: Vec<i64> = (0..columns).map(|_| rng.gen_range(1..=size.height.max(1) as i64)).collect();
+
+    // Along the tops to the right, down the last column, and back along the bottoms to the left.
+    let mut steps: Vec<(char, u64)> = Vec::new();
+    let mut dig = |direction: char, distance: u64| {
+        if distance == 0 {
+            return;
+        }
+        match steps.last_mut() {
+            Some((last_direction, last_distance)) if *last_direction == direction => *last_distance += distance,
+            _ => steps.push((direction, distance))
+        }
+    };
+    let vertical = |from: i64, to: i64| if to < from { ('U', from.abs_diff(to)) } else { ('D', from.abs_diff(to)) };
+    for column in 0..columns {
+        dig('R', widths[column]);
+        let (direction, distance) = vertical(tops[column], *tops.get(column + 1).unwrap_or(&bottoms[column]));
+        dig(direction, distance);
+    }
+    for column in (0..columns).rev() {
+        dig('L', widths[column]);
+        let (direction, distance) = vertical(bottoms[column], *bottoms.get(column.wrapping_sub(1)).unwrap_or(&tops[0]));
+        dig(direction, distance);
+    }
+
+    let scale = (MAX_CORRECTED_DISTANCE / steps.iter().map(|(_, distance)| *distance).max().unwrap()).max(1);
+    let scale = rng.gen_range(1..=scale);
+    let mut input = String::new();
+    for (direction, distance) in steps {
+        let direction_digit = match direction {
+            'R' => 0,
+            'D' => 1,
+            'L' => 2,
+            _ => 3
+        };
+        input.push_str(&format!("{direction} {distance} (#{:05x}{direction_digit})\n", distance * scale));
+    }
+    return input;
+}
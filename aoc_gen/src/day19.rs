@@ -0,0 +1,44 @@
+//--------------------------------------------------------------------------------
+// Day 19: Random workflows and parts
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{unique_names, Size};
+
+const LETTERS: [char; 26] = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+                             'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'];
+
+/// `count` workflows of up to four rules each, starting with `in`, and `height` parts. Rules only send parts to
+/// workflows later in the list, so every part ends up accepted or rejected.
+pub fn generate(rng: &mut impl Rng, size: &Size) -> String {
+    let count = size.count.max(1);
+    let mut names = unique_names(rng, count - 1, 3, &LETTERS, &["in"]);
+    names.insert(0, "in".to_string());
+
+    let mut workflows: Vec<String> = Vec::new();
+    for (i, name) in names.iter().enumerate() {
+        let mut destinations: Vec<&str> = vec!["A", "R"];
+        destinations.extend(names[i + 1..].iter().map(String::as_str));
+
+        let mut rules: Vec<String> = Vec::new();
+        for _ in 0..rng.gen_range(1..=3) {
+            let rating = ["x", "m", "a", "s"].choose(rng).unwrap();
+            let relation = ["<", ">"].choose(rng).unwrap();
+            rules.push(format!("{rating}{relation}{}:{}", rng.gen_range(1..4000), destinations.choose(rng).unwrap()));
+        }
+        rules.push(destinations.choose(rng).unwrap().to_string());
+        workflows.push(format!("{name}{{{}}}", rules.join(",")));
+    }
+    workflows.shuffle(rng);
+
+    let mut input = workflows.join("\n");
+    input.push_str("\n\n");
+    for _ in 0..size.height.max(1) {
+        let [x, m, a, s]: [u32; 4] = std::array::from_fn(|_| rng.gen_range(1..=4000));
+        input.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+    }
+    return input;
+}
@@ -0,0 +1,141 @@
+//--------------------------------------------------------------------------------
+// Gen: Random puzzle inputs of any size
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Generators of random but valid puzzle inputs for every day, to see how the solvers scale past the one real input.
+//!
+//! Every generator draws from a seeded [`ChaCha8Rng`], so the same day, seed and [`Size`] always give the same input.
+//! Inputs are also solvable: there is a loop through `S` on day 10, a way from `AAA` to `ZZZ` on day 08, exactly one
+//! reflection and one smudged reflection in every pattern on day 13, and so on.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// How big a generated input should be. Each day reads the fields that make sense for its puzzle, as described on its
+/// `generate`, and raises any that are too small to make a valid input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    /// How many things the input lists: lines, games, cards, hands, records, patterns, workflows and so on.
+    pub count: usize,
+    pub width: usize,
+    pub height: usize
+}
+
+impl Default for Size {
+    fn default() -> Self {
+        return Size { count: 10, width: 10, height: 10 };
+    }
+}
+
+/// The random number generator every generator draws from, seeded with `seed`.
+pub fn rng(seed: u64) -> ChaCha8Rng {
+    return ChaCha8Rng::seed_from_u64(seed);
+}
+
+/// A random input for `day`, or `None` if there is no generator for it.
+pub fn generate(day: u8, seed: u64, size: &Size) -> Option<String> {
+    let mut rng = rng(seed);
+    let input = match day {
+        1 => day01::generate(&mut rng, size),
+        2 => day02::generate(&mut rng, size),
+        3 => day03::generate(&mut rng, size),
+        4 => day04::generate(&mut rng, size),
+        5 => day05::generate(&mut rng, size),
+        6 => day06::generate(&mut rng, size),
+        7 => day07::generate(&mut rng, size),
+        8 => day08::generate(&mut rng, size),
+        9 => day09::generate(&mut rng, size),
+        10 => day10::generate(&mut rng, size),
+        11 => day11::generate(&mut rng, size),
+        12 => day12::generate(&mut rng, size),
+        13 => day13::generate(&mut rng, size),
+        14 => day14::generate(&mut rng, size),
+        15 => day15::generate(&mut rng, size),
+        16 => day16::generate(&mut rng, size),
+        17 => day17::generate(&mut rng, size),
+        18 => day18::generate(&mut rng, size),
+        19 => day19::generate(&mut rng, size),
+        _ => return None
+    };
+    return Some(input);
+}
+
+/// A `width` by `height` grid of tiles drawn from `tiles`, where each tile is paired with how often it should appear.
+pub fn grid(rng: &mut impl Rng, width: usize, height: usize, tiles: &[(char, u32)]) -> Vec<Vec<char>> {
+    return (0..height)
+        .map(|_| (0..width).map(|_| tiles.choose_weighted(rng, |(_, weight)| *weight).unwrap().0).collect())
+        .collect();
+}
+
+/// The rows of `grid`, one per line.
+pub fn grid_to_string(grid: &Vec<Vec<char>>) -> String {
+    let mut input = String::new();
+    for row in grid {
+        input.extend(row);
+        input.push('\n');
+    }
+    return input;
+}
+
+/// `count` different strings of `length` characters drawn from `alphabet`, none of them in `taken`.
+pub fn unique_names(rng: &mut impl Rng, count: usize, length: usize, alphabet: &[char], taken: &[&str]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    while names.len() < count {
+        let name: String = (0..length).map(|_| *alphabet.choose(rng).unwrap()).collect();
+        if !taken.contains(&name.as_str()) && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    return names;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{generate, Size};
+
+    #[test]
+    fn test_generate_is_seeded() {
+        let size = Size::default();
+        for day in 1..=19 {
+            let input = generate(day, 7, &size).unwrap();
+            assert!(!input.is_empty());
+            assert_eq!(generate(day, 7, &size), Some(input.clone()), "day {day}");
+            assert_ne!(generate(day, 8, &size), Some(input), "day {day}");
+        }
+        assert_eq!(generate(25, 7, &size), None);
+    }
+
+    #[test]
+    fn test_generate_scales() {
+        let small = generate(17, 0, &Size { count: 1, width: 7, height: 6 }).unwrap();
+        assert_eq!(small.lines().count(), 6);
+        assert!(small.lines().all(|line| line.len() == 7));
+        let tiny = generate(17, 0, &Size { count: 1, width: 3, height: 2 }).unwrap();
+        assert_eq!((tiny.lines().count(), tiny.lines().next().unwrap().len()), (2, 3));
+
+        let large = generate(7, 0, &Size { count: 1000, ..Size::default() }).unwrap();
+        assert_eq!(large.lines().count(), 1000);
+    }
+}
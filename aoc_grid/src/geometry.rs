@@ -0,0 +1,198 @@
+//--------------------------------------------------------------------------------
+// Grid: Positions and the directions to move between them
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+/// One of the four ways to move to a tile sharing an edge, as seen on a map with north at the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The direction a quarter turn counter-clockwise from this one.
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// The direction a quarter turn clockwise from this one.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// The opposite direction.
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// How a single step changes `x` and `y`. `y` grows downwards.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        return matches!(self, Direction::Left | Direction::Right);
+    }
+
+    /// Reads an arrow: `^`, `>`, `v` or `<`.
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    /// Reads the initial of the direction: `U`, `R`, `D` or `L`.
+    pub fn from_letter(letter: char) -> Option<Direction> {
+        match letter {
+            'U' => Some(Direction::Up),
+            'R' => Some(Direction::Right),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            _ => None
+        }
+    }
+
+    pub fn letter(self) -> char {
+        match self {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// A tile's place on a grid: `x` counts columns from the left, `y` counts rows from the top.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize
+}
+
+impl Pos {
+    pub const fn new(x: usize, y: usize) -> Pos {
+        return Pos { x, y };
+    }
+
+    /// The position `dx` columns right and `dy` rows down, unless that is left of or above the origin.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Pos> {
+        let x = self.x.checked_add_signed(dx)?;
+        let y = self.y.checked_add_signed(dy)?;
+        return Some(Pos { x, y });
+    }
+
+    /// The position next to this one in `direction`, unless that is left of or above the origin.
+    pub fn step(self, direction: Direction) -> Option<Pos> {
+        let (dx, dy) = direction.delta();
+        return self.offset(dx, dy);
+    }
+
+    /// The position `distance` steps away in `direction`, unless that is left of or above the origin.
+    pub fn steps(self, direction: Direction, distance: usize) -> Option<Pos> {
+        let (dx, dy) = direction.delta();
+        let distance = isize::try_from(distance).ok()?;
+        return self.offset(dx.checked_mul(distance)?, dy.checked_mul(distance)?);
+    }
+
+    /// The length of the shortest path to `other` moving only up, down, left or right.
+    pub fn manhattan_distance(self, other: Pos) -> usize {
+        return self.x.abs_diff(other.x) + self.y.abs_diff(other.y);
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from(value: (usize, usize)) -> Self {
+        return Pos { x: value.0, y: value.1 };
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::{Direction, Pos};
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_ne!(direction.is_horizontal(), direction.turn_left().is_horizontal());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn test_notations() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+            assert_eq!(Direction::from_letter(direction.letter()), Some(direction));
+        }
+        assert_eq!(Direction::from_arrow('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_letter('L'), Some(Direction::Left));
+        assert_eq!(Direction::from_letter('x'), None);
+    }
+
+    #[test]
+    fn test_steps() {
+        let pos = Pos::new(2, 1);
+        assert_eq!(pos.step(Direction::Up), Some(Pos::new(2, 0)));
+        assert_eq!(pos.steps(Direction::Right, 3), Some(Pos::new(5, 1)));
+        assert_eq!(pos.steps(Direction::Up, 2), None);
+        assert_eq!(pos.steps(Direction::Left, 2), Some(Pos::new(0, 1)));
+        assert_eq!(pos.manhattan_distance(Pos::new(0, 4)), 5);
+    }
+}
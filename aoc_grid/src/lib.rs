@@ -0,0 +1,333 @@
+//--------------------------------------------------------------------------------
+// Grid: The rectangle of tiles most puzzles are drawn on
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! A rectangular grid of tiles stored row by row in one `Vec`, for the days whose input is a map.
+
+mod geometry;
+
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use aoc_common::{lines, Line, ParseError};
+
+pub use geometry::{Direction, Pos};
+
+/// The steps to the eight tiles around a tile, clockwise from up.
+const NEIGHBOURS_8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A rectangle of tiles, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    tiles: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// A grid `width` tiles wide holding `tiles` row by row, or `None` if they do not fill whole rows.
+    pub fn from_vec(width: usize, tiles: Vec<T>) -> Option<Grid<T>> {
+        if width == 0 || !tiles.len().is_multiple_of(width) {
+            return None;
+        }
+        let height = tiles.len() / width;
+        return Some(Grid { width, height, tiles });
+    }
+
+    /// A grid made of `rows`, or `None` if they are not all as wide as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first()?.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        return Grid::from_vec(width, rows.into_iter().flatten().collect());
+    }
+
+    /// A grid whose tiles are made by calling `tile` with each position, in reading order.
+    pub fn from_fn(width: usize, height: usize, mut tile: impl FnMut(Pos) -> T) -> Grid<T> {
+        let tiles = (0..height)
+                        .flat_map(|y| (0..width).map(move |x| Pos { x, y }))
+                        .map(&mut tile)
+                        .collect();
+        return Grid { width, height, tiles };
+    }
+
+    /// A grid with `tile` everywhere.
+    pub fn filled(width: usize, height: usize, tile: T) -> Grid<T> where T: Clone {
+        return Grid { width, height, tiles: vec![tile; width * height] };
+    }
+
+    /// Reads a grid with one tile per character, turning each character into a tile with `tile`.
+    pub fn parse_with<E: Display>(input: &str, tile: impl FnMut(char) -> Result<T, E>) -> Result<Grid<T>, ParseError> {
+        return Grid::from_lines_with(lines(input), tile);
+    }
+
+    /// Reads a grid from some of the lines of an input, so that errors point at the right line.
+    pub fn from_lines_with<'a, E: Display>(lines: impl IntoIterator<Item = Line<'a>>, mut tile: impl FnMut(char) -> Result<T, E>) -> Result<Grid<T>, ParseError> {
+        let mut width: Option<usize> = None;
+        let mut tiles: Vec<T> = Vec::new();
+        let mut next_line: usize = 1;
+
+        for line in lines {
+            let row_start = tiles.len();
+            for (i, character) in line.text.char_indices() {
+                let next_tile = tile(character)
+                                    .map_err(|error| line.error_at(i + 1, format!("unexpected character `{}`: {error}", character.escape_debug())))?;
+                tiles.push(next_tile);
+            }
+            let row_width = tiles.len() - row_start;
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(line.error_at(1, format!("expected a row {width} tiles wide, found {row_width}")));
+                },
+                _ => width = Some(row_width)
+            }
+            next_line = line.number + 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => return Ok(Grid { width, height: tiles.len() / width, tiles }),
+            _ => return Err(ParseError::new(next_line, 1, "expected a grid"))
+        }
+    }
+
+    /// The number of tiles in a row.
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Whether `pos` is on the grid.
+    pub fn contains(&self, pos: Pos) -> bool {
+        return pos.x < self.width && pos.y < self.height;
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return self.tiles.get(pos.y * self.width + pos.x);
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return self.tiles.get_mut(pos.y * self.width + pos.x);
+    }
+
+    /// The position `dx` columns right and `dy` rows down from `pos`, unless that is off the grid.
+    pub fn offset(&self, pos: Pos, dx: isize, dy: isize) -> Option<Pos> {
+        return pos.offset(dx, dy).filter(|next| self.contains(*next));
+    }
+
+    /// The position next to `pos` in `direction`, unless that is off the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        return pos.step(direction).filter(|next| self.contains(*next));
+    }
+
+    /// The position `distance` steps from `pos` in `direction`, unless that is off the grid.
+    pub fn steps(&self, pos: Pos, direction: Direction, distance: usize) -> Option<Pos> {
+        return pos.steps(direction, distance).filter(|next| self.contains(*next));
+    }
+
+    /// The positions on the grid sharing an edge with `pos`: up, right, down, then left.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        return Direction::ALL.into_iter().filter_map(move |direction| self.step(pos, direction));
+    }
+
+    /// The positions on the grid around `pos`, diagonals included, clockwise from up.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        return NEIGHBOURS_8.into_iter().filter_map(move |(dx, dy)| self.offset(pos, dx, dy));
+    }
+
+    /// Every position on the grid, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos { x, y }));
+    }
+
+    /// Every tile and its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        return self.positions().zip(self.tiles.iter());
+    }
+
+    /// The first position, in reading order, whose tile satisfies `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        return self.iter().find(|(_, tile)| predicate(tile)).map(|(pos, _)| pos);
+    }
+
+    /// Every tile, row by row.
+    pub fn tiles(&self) -> &[T] {
+        return &self.tiles;
+    }
+
+    /// The `y`th row, from the top.
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.tiles[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        return &mut self.tiles[y * self.width..(y + 1) * self.width];
+    }
+
+    /// Every row, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.tiles.chunks(self.width);
+    }
+
+    /// The tiles of the `x`th column, from the top.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        return self.tiles[x..].iter().step_by(self.width);
+    }
+
+    /// Every column, from the left.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(|x| self.column(x));
+    }
+
+    /// A grid of the same size with `f` applied to every tile.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid { width: self.width, height: self.height, tiles: self.tiles.iter().map(f).collect() };
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.height, self.width, |pos| self[Pos { x: pos.y, y: pos.x }].clone());
+    }
+
+    /// The grid turned a quarter clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.height, self.width, |pos| self[Pos { x: pos.y, y: self.height - 1 - pos.x }].clone());
+    }
+
+    /// The grid turned a quarter counter-clockwise, so the right column becomes the top row.
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.height, self.width, |pos| self[Pos { x: self.width - 1 - pos.y, y: pos.x }].clone());
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.width, self.height, |pos| self[Pos { x: self.width - 1 - pos.x, y: pos.y }].clone());
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> where T: Clone {
+        return Grid::from_fn(self.width, self.height, |pos| self[Pos { x: pos.x, y: self.height - 1 - pos.y }].clone());
+    }
+}
+
+impl<T> Grid<T> where T: TryFrom<char>, T::Error: Display {
+    /// Reads a grid with one tile per character.
+    pub fn parse(input: &str) -> Result<Grid<T>, ParseError> {
+        return Grid::parse_with(input, T::try_from);
+    }
+
+    /// Reads a grid with one tile per character from some of the lines of an input.
+    pub fn from_lines<'a>(lines: impl IntoIterator<Item = Line<'a>>) -> Result<Grid<T>, ParseError> {
+        return Grid::from_lines_with(lines, T::try_from);
+    }
+}
+
+impl<T> FromStr for Grid<T> where T: TryFrom<char>, T::Error: Display {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Grid::parse(input);
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        assert!(self.contains(pos), "{pos} is off the {}×{} grid", self.width, self.height);
+        return &self.tiles[pos.y * self.width + pos.x];
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        assert!(self.contains(pos), "{pos} is off the {}×{} grid", self.width, self.height);
+        return &mut self.tiles[pos.y * self.width + pos.x];
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::lines;
+
+    use crate::{Grid, Pos};
+
+    fn numbers() -> Grid<char> {
+        return Grid::parse("123\n456\n").unwrap();
+    }
+
+    #[test]
+    fn test_index() {
+        let mut grid = numbers();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], '6');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.position(|tile| *tile == '5'), Some(Pos::new(1, 1)));
+
+        grid[Pos::new(0, 0)] = '0';
+        assert_eq!(grid.row(0), ['0', '2', '3']);
+        assert_eq!(grid.column(1).collect::<String>(), "25");
+        assert_eq!(grid.columns().map(|column| column.collect::<String>()).collect::<Vec<String>>(), ["04", "25", "36"]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = numbers();
+        assert_eq!(grid.neighbours4(Pos::new(0, 0)).collect::<Vec<Pos>>(), [Pos::new(1, 0), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+        assert_eq!(grid.offset(Pos::new(2, 1), 1, 0), None);
+        assert_eq!(Pos::new(0, 0).offset(-1, 0), None);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = numbers();
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654\n");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+        assert_eq!(Grid::from_rows(vec![vec!['1', '2', '3'], vec!['4', '5', '6']]), Some(grid));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::<char>::parse("12\n345").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = Grid::parse_with("12\n3x", |tile| tile.to_digit(10).ok_or("not a digit")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "unexpected character `x`: not a digit");
+
+        let error = Grid::<char>::from_lines(lines("ab\n\ncd\nefg").skip(2)).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert!(Grid::<char>::parse("").is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//--------------------------------------------------------------------------------
+// Interval: Boxes of ranges in any number of dimensions
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use std::{cmp, ops::Range};
+
+/// Every point whose coordinates each fall within the range of their axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalBox<const N: usize> {
+    pub axes: [Range<i64>; N]
+}
+
+impl<const N: usize> IntervalBox<N> {
+    pub fn new(axes: [Range<i64>; N]) -> IntervalBox<N> {
+        return IntervalBox { axes };
+    }
+
+    /// Whether some axis has no numbers in it, which leaves the box without any points.
+    pub fn is_empty(&self) -> bool {
+        return self.axes.iter().any(|axis| axis.is_empty());
+    }
+
+    /// How many points are in the box.
+    pub fn volume(&self) -> u64 {
+        if self.is_empty() {
+            return 0;
+        }
+        return self.axes.iter().map(|axis| axis.start.abs_diff(axis.end)).product();
+    }
+
+    pub fn contains(&self, point: &[i64; N]) -> bool {
+        return self.axes.iter().zip(point).all(|(axis, coordinate)| axis.contains(coordinate));
+    }
+
+    /// The points in both boxes, unless there are none.
+    pub fn intersection(&self, other: &IntervalBox<N>) -> Option<IntervalBox<N>> {
+        let intersection = IntervalBox {
+            axes: std::array::from_fn(|i| cmp::max(self.axes[i].start, other.axes[i].start)..cmp::min(self.axes[i].end, other.axes[i].end))
+        };
+        if intersection.is_empty() {
+            return None;
+        }
+        return Some(intersection);
+    }
+
+    /// Cuts the box across `axis` into the points below `point` on that axis and the points from `point` upwards.
+    /// Either side is `None` if it would be empty.
+    pub fn split_at(&self, axis: usize, point: i64) -> (Option<IntervalBox<N>>, Option<IntervalBox<N>>) {
+        let mut below = self.clone();
+        let mut above = self.clone();
+        below.axes[axis].end = cmp::min(self.axes[axis].end, point);
+        above.axes[axis].start = cmp::max(self.axes[axis].start, point);
+        return ((!below.is_empty()).then_some(below), (!above.is_empty()).then_some(above));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interval_box::IntervalBox;
+
+    #[test]
+    fn test_volume() {
+        let cube = IntervalBox::new([0..4, 0..4, 0..4]);
+        assert_eq!(cube.volume(), 64);
+        assert!(cube.contains(&[3, 0, 2]) && !cube.contains(&[4, 0, 2]));
+        assert_eq!(IntervalBox::new([0..4, 3..3]).volume(), 0);
+
+        let other = IntervalBox::new([2..6, -1..1, 1..3]);
+        assert_eq!(cube.intersection(&other), Some(IntervalBox::new([2..4, 0..1, 1..3])));
+        assert_eq!(cube.intersection(&IntervalBox::new([4..6, 0..4, 0..4])), None);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let square = IntervalBox::new([1..11, 1..11]);
+        let (below, above) = square.split_at(1, 4);
+        assert_eq!(below, Some(IntervalBox::new([1..11, 1..4])));
+        assert_eq!(above, Some(IntervalBox::new([1..11, 4..11])));
+        assert_eq!(below.unwrap().volume() + above.unwrap().volume(), square.volume());
+
+        assert_eq!(square.split_at(0, 1), (None, Some(square.clone())));
+        assert_eq!(square.split_at(0, 20), (Some(square.clone()), None));
+    }
+}
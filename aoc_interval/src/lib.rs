@@ -0,0 +1,264 @@
+//--------------------------------------------------------------------------------
+// Interval: Sets and maps of whole ranges of numbers
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Sets of numbers stored as sorted, disjoint ranges, maps that shift each range by its own offset, and boxes of ranges
+//! in any number of dimensions, for the days that would never finish if they looked at one number at a time.
+//!
+//! Every range is half-open, like [`std::ops::Range`]: `start` is included and `end` is not.
+
+mod interval_box;
+
+use std::{cmp, ops::Range};
+
+pub use interval_box::IntervalBox;
+
+/// A set of integers, stored as the sorted, disjoint ranges that cover it. Ranges that touch are merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        return IntervalSet { ranges: Vec::new() };
+    }
+
+    /// Adds every number in `range` to the set.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        let mut merged: Vec<Range<i64>> = Vec::with_capacity(self.ranges.len() + 1);
+        let mut inserted = false;
+        for existing in self.ranges.drain(..) {
+            if existing.end < start {
+                merged.push(existing);
+            } else if end < existing.start {
+                if !inserted {
+                    merged.push(start..end);
+                    inserted = true;
+                }
+                merged.push(existing);
+            } else {
+                start = cmp::min(start, existing.start);
+                end = cmp::max(end, existing.end);
+            }
+        }
+        if !inserted {
+            merged.push(start..end);
+        }
+        self.ranges = merged;
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        return self.ranges.get(index).is_some_and(|range| range.start <= value);
+    }
+
+    /// How many numbers are in the set.
+    pub fn len(&self) -> u64 {
+        return self.ranges.iter().map(|range| range.start.abs_diff(range.end)).sum();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ranges.is_empty();
+    }
+
+    /// The smallest number in the set.
+    pub fn min(&self) -> Option<i64> {
+        return self.ranges.first().map(|range| range.start);
+    }
+
+    /// The ranges covering the set, from the lowest.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        return &self.ranges;
+    }
+
+    /// The numbers in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        return union;
+    }
+
+    /// The numbers in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intersection = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (left, right) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = cmp::max(left.start, right.start)..cmp::min(left.end, right.end);
+            if !overlap.is_empty() {
+                intersection.ranges.push(overlap);
+            }
+            // Whichever range ends first cannot overlap anything else in the other set.
+            if left.end < right.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        return intersection;
+    }
+
+    /// The numbers in this set but not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = IntervalSet::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            let first = other.ranges.partition_point(|removed| removed.end <= start);
+            for removed in &other.ranges[first..] {
+                if removed.start >= range.end {
+                    break;
+                }
+                if removed.start > start {
+                    difference.ranges.push(start..removed.start);
+                }
+                start = cmp::max(start, removed.end);
+            }
+            if start < range.end {
+                difference.ranges.push(start..range.end);
+            }
+        }
+        return difference;
+    }
+
+    /// Splits the set into the numbers below `point` and the numbers from `point` upwards.
+    pub fn split_at(&self, point: i64) -> (IntervalSet, IntervalSet) {
+        let (mut below, mut above) = (IntervalSet::new(), IntervalSet::new());
+        for range in &self.ranges {
+            if range.end <= point {
+                below.ranges.push(range.clone());
+            } else if range.start >= point {
+                above.ranges.push(range.clone());
+            } else {
+                below.ranges.push(range.start..point);
+                above.ranges.push(point..range.end);
+            }
+        }
+        return (below, above);
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        return set;
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        return set;
+    }
+}
+
+/// A map that shifts the numbers of each of its ranges by that range's offset. Numbers outside every range map onto themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalMap {
+    /// Sorted, disjoint ranges and their offsets.
+    pieces: Vec<(Range<i64>, i64)>
+}
+
+impl IntervalMap {
+    pub fn new() -> IntervalMap {
+        return IntervalMap { pieces: Vec::new() };
+    }
+
+    /// Shifts the numbers in `range` by `offset`. Numbers that an earlier range already covers keep their offset.
+    pub fn insert(&mut self, range: Range<i64>, offset: i64) {
+        let uncovered = IntervalSet::from(range).difference(&self.domain());
+        for piece in uncovered.ranges {
+            let index = self.pieces.partition_point(|(existing, _)| existing.start < piece.start);
+            self.pieces.insert(index, (piece, offset));
+        }
+    }
+
+    /// Where the map sends `value`.
+    pub fn get(&self, value: i64) -> i64 {
+        let index = self.pieces.partition_point(|(range, _)| range.end <= value);
+        match self.pieces.get(index) {
+            Some((range, offset)) if range.start <= value => value + offset,
+            _ => value
+        }
+    }
+
+    /// The numbers that the map shifts.
+    pub fn domain(&self) -> IntervalSet {
+        return IntervalSet { ranges: self.pieces.iter().map(|(range, _)| range.clone()).collect() };
+    }
+
+    /// Where the map sends every number of `set`.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut image = set.difference(&self.domain());
+        for (range, offset) in &self.pieces {
+            for overlap in set.intersection(&IntervalSet::from(range.clone())).ranges {
+                image.insert((overlap.start + offset)..(overlap.end + offset));
+            }
+        }
+        return image;
+    }
+
+    /// The ranges of the map and their offsets, from the lowest.
+    pub fn pieces(&self) -> &[(Range<i64>, i64)] {
+        return &self.pieces;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{IntervalMap, IntervalSet};
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::new();
+        set.insert(10..20);
+        set.insert(0..5);
+        set.insert(30..40);
+        set.insert(5..7);
+        set.insert(15..32);
+        set.insert(50..50);
+        assert_eq!(set.ranges(), &[0..7, 10..40]);
+        assert_eq!(set.len(), 37);
+        assert_eq!(set.min(), Some(0));
+        assert!(set.contains(6) && !set.contains(7) && set.contains(39) && !set.contains(40));
+    }
+
+    #[test]
+    fn test_operations() {
+        let left: IntervalSet = [0..10, 20..30].into_iter().collect();
+        let right: IntervalSet = [5..25, 28..29].into_iter().collect();
+        assert_eq!(left.union(&right), IntervalSet::from(0..30));
+        assert_eq!(left.intersection(&right).ranges(), &[5..10, 20..25, 28..29]);
+        assert_eq!(left.difference(&right).ranges(), &[0..5, 25..28, 29..30]);
+        assert_eq!(right.difference(&left), IntervalSet::from(10..20));
+
+        let (below, above) = left.split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(above, IntervalSet::from(25..30));
+    }
+
+    #[test]
+    fn test_map() {
+        // The seed-to-soil map of day 5's example.
+        let mut map = IntervalMap::new();
+        map.insert(98..100, -48);
+        map.insert(50..98, 2);
+        map.insert(60..70, 100);
+        assert_eq!(map.pieces().len(), 2);
+        assert_eq!([map.get(79), map.get(14), map.get(55), map.get(98), map.get(100)], [81, 14, 57, 50, 100]);
+
+        let image = map.map_set(&[45..55, 95..105].into_iter().collect());
+        assert_eq!(image.ranges(), &[45..57, 97..105]);
+    }
+}
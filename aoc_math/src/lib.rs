@@ -0,0 +1,145 @@
+//--------------------------------------------------------------------------------
+// Math: Number theory shared by the days
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Exact integer arithmetic the puzzles keep coming back to: divisors, congruences, square roots and the area of lattice polygons.
+
+mod polygon;
+
+pub use polygon::{boundary_points, double_area, interior_points, lattice_points};
+
+/// The greatest common divisor of `a` and `b`. The divisor of `0` and `0` is `0`.
+pub fn gcd(a: u128, b: u128) -> u128 {
+    let mut numbers = (a, b);
+    while numbers.1 != 0 {
+        numbers = (numbers.1, numbers.0 % numbers.1);
+    }
+    return numbers.0;
+}
+
+/// The least common multiple of `a` and `b`, unless it does not fit in a `u128`.
+pub fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    return (a / gcd(a, b)).checked_mul(b);
+}
+
+/// The least common multiple of every value, unless there are none or it does not fit in a `u128`.
+pub fn lcm_multiple<I>(values: I) -> Option<u128> where I: IntoIterator<Item=u128> {
+    let mut values_iter = values.into_iter();
+    let mut current_lcm = values_iter.next()?;
+    for value in values_iter {
+        current_lcm = lcm(current_lcm, value)?;
+    }
+    return Some(current_lcm);
+}
+
+/// Bézout's identity: returns `(g, x, y)` such that `a * x + b * y = g`, where `g` is the non-negative greatest common divisor of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+    return (old_r, old_x, old_y);
+}
+
+/// Chinese remainder theorem: given congruences `x ≡ residue (mod modulus)`, returns the smallest non-negative `x` satisfying all of them
+/// and the modulus it repeats with. The moduli need not be coprime, but must be positive.
+/// Returns `None` if the congruences contradict each other or the combined modulus does not fit in an `i128`.
+pub fn chinese_remainder<I>(congruences: I) -> Option<(i128, i128)> where I: IntoIterator<Item=(i128, i128)> {
+    let mut solution: (i128, i128) = (0, 1);
+    for (residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (current, current_modulus) = solution;
+        // current + current_modulus * k ≡ residue (mod modulus) is solvable only if the divisor divides the difference.
+        let (divisor, inverse, _) = extended_gcd(current_modulus, modulus);
+        let difference = residue - current;
+        if difference % divisor != 0 {
+            return None;
+        }
+        let step_modulus = modulus / divisor;
+        let k = ((difference / divisor) % step_modulus * (inverse % step_modulus)).rem_euclid(step_modulus);
+        let combined_modulus = current_modulus.checked_mul(step_modulus)?;
+        let combined = (current + current_modulus.checked_mul(k)?).rem_euclid(combined_modulus);
+        solution = (combined, combined_modulus);
+    }
+    return Some(solution);
+}
+
+/// The largest integer whose square is at most `n`.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from a power of two above the root: the estimate only decreases until it reaches the floor of the root.
+    let mut estimate = 1_u128 << (128 - n.leading_zeros()).div_ceil(2);
+    let mut next = (estimate + n / estimate) / 2;
+    while next < estimate {
+        estimate = next;
+        next = (estimate + n / estimate) / 2;
+    }
+    return estimate;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{chinese_remainder, extended_gcd, gcd, isqrt, lcm, lcm_multiple};
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(42, 56), 14);
+        assert_eq!(gcd(2, 0), 2);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(1, 1), 1);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u128::MAX, 2), None);
+        assert_eq!(lcm_multiple([2, 3, 4]), Some(12));
+        assert_eq!(lcm_multiple([]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (35, 15)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i128);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(chinese_remainder([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(chinese_remainder([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(chinese_remainder([(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder([(-1, 5)]), Some((4, 5)));
+        assert_eq!(chinese_remainder([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0..1000_u128 {
+            let root = isqrt(n);
+            assert!(root * root <= n && n < (root + 1) * (root + 1));
+        }
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt(10_u128.pow(30)), 10_u128.pow(15));
+    }
+}
@@ -0,0 +1,72 @@
+//--------------------------------------------------------------------------------
+// Math: Polygons drawn on the integer lattice
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+use crate::gcd;
+
+/// Twice the area of the polygon with the given `vertices`, by the shoelace formula. Twice the area is always an integer.
+/// The polygon closes from the last vertex back to the first, and may go around in either direction.
+pub fn double_area(vertices: &Vec<(i128, i128)>) -> i128 {
+    let mut area: i128 = 0;
+    for (i, &(x1, y1)) in vertices.iter().enumerate() {
+        let (x2, y2) = vertices[(i + 1) % vertices.len()];
+        area += x1 * y2 - x2 * y1;
+    }
+    return area.abs();
+}
+
+/// The number of lattice points on the edges of the polygon with the given `vertices`.
+pub fn boundary_points(vertices: &Vec<(i128, i128)>) -> i128 {
+    let mut points: i128 = 0;
+    for (i, &(x1, y1)) in vertices.iter().enumerate() {
+        let (x2, y2) = vertices[(i + 1) % vertices.len()];
+        points += gcd((x2 - x1).unsigned_abs(), (y2 - y1).unsigned_abs()) as i128;
+    }
+    return points;
+}
+
+/// The number of lattice points strictly inside the polygon with the given `vertices`, by Pick's theorem: `A = i + b/2 - 1`.
+pub fn interior_points(vertices: &Vec<(i128, i128)>) -> i128 {
+    if vertices.len() < 3 {
+        return 0;
+    }
+    return (double_area(vertices) - boundary_points(vertices) + 2) / 2;
+}
+
+/// The number of lattice points inside the polygon with the given `vertices` or on its edges.
+pub fn lattice_points(vertices: &Vec<(i128, i128)>) -> i128 {
+    return interior_points(vertices) + boundary_points(vertices);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::polygon::{boundary_points, double_area, interior_points, lattice_points};
+
+    #[test]
+    fn test_square() {
+        let square = vec![(0, 0), (4, 0), (4, 4), (0, 4)];
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+        assert_eq!(lattice_points(&square), 25);
+
+        let mut reversed = square.clone();
+        reversed.reverse();
+        assert_eq!(double_area(&reversed), 32);
+    }
+
+    #[test]
+    fn test_triangle() {
+        let triangle = vec![(0, 0), (3, 0), (0, 3)];
+        assert_eq!(double_area(&triangle), 9);
+        assert_eq!(boundary_points(&triangle), 9);
+        assert_eq!(interior_points(&triangle), 1);
+    }
+
+    #[test]
+    fn test_repeated_vertex() {
+        let square = vec![(0, 0), (2, 0), (2, 2), (0, 2), (0, 0)];
+        assert_eq!(lattice_points(&square), 9);
+    }
+}
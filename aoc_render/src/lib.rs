@@ -0,0 +1,273 @@
+//--------------------------------------------------------------------------------
+// Render: Pictures of grids and the paths across them
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Draws the state of a puzzle as a picture, to see what a solver did instead of squinting at printed grids.
+//!
+//! A [`Picture`] is a grid of coloured cells with lines drawn over it, in the same coordinates as the puzzle: cell
+//! `(x, y)` covers the square from `(x, y)` to `(x + 1, y + 1)`, so its centre is at `(x + 0.5, y + 0.5)`.
+//! It can be written out as an SVG, which scales to any size, or as a PNG with each cell a fixed number of pixels.
+
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use aoc_grid::{Grid, Pos};
+
+/// A colour, as red, green and blue from 0 to 255.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::from_hex(0x000000);
+    pub const WHITE: Colour = Colour::from_hex(0xffffff);
+
+    /// The colour written `0xrrggbb`.
+    pub const fn from_hex(hex: u32) -> Colour {
+        return Colour { r: (hex >> 16) as u8, g: (hex >> 8) as u8, b: hex as u8 };
+    }
+
+    /// The colour as `#rrggbb`, the way SVG writes it.
+    pub fn to_hex(self) -> String {
+        return format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b);
+    }
+
+    /// The colour `t` of the way from `self` to `other`, where `t` runs from 0 to 1.
+    pub fn mix(self, other: Colour, t: f64) -> Colour {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+        return Colour { r: channel(self.r, other.r), g: channel(self.g, other.g), b: channel(self.b, other.b) };
+    }
+}
+
+/// A line through a series of points, drawn over the cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub points: Vec<(f64, f64)>,
+    pub colour: Colour,
+    /// How thick the line is, as a fraction of a cell.
+    pub width: f64
+}
+
+/// A grid of coloured cells with lines drawn over it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picture {
+    pub cells: Grid<Colour>,
+    pub strokes: Vec<Stroke>
+}
+
+impl Picture {
+    /// A `width` by `height` picture with every cell `background`.
+    pub fn new(width: usize, height: usize, background: Colour) -> Picture {
+        return Picture { cells: Grid::filled(width, height, background), strokes: Vec::new() };
+    }
+
+    /// A picture of `grid`, with each tile the colour `colour` gives it.
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl FnMut(&T) -> Colour) -> Picture {
+        return Picture { cells: grid.map(colour), strokes: Vec::new() };
+    }
+
+    pub fn width(&self) -> usize {
+        return self.cells.width();
+    }
+
+    pub fn height(&self) -> usize {
+        return self.cells.height();
+    }
+
+    /// Colours the cell at `pos`, if it is in the picture.
+    pub fn fill(&mut self, pos: Pos, colour: Colour) {
+        if let Some(cell) = self.cells.get_mut(pos) {
+            *cell = colour;
+        }
+    }
+
+    /// Draws a line through the centres of the cells at `positions`, in order.
+    pub fn path(&mut self, positions: &[Pos], colour: Colour, width: f64) {
+        let points = positions.iter().map(|pos| (pos.x as f64 + 0.5, pos.y as f64 + 0.5)).collect();
+        self.strokes.push(Stroke { points, colour, width });
+    }
+
+    /// The pictures side by side, left to right, `gap` cells of `background` apart and lined up along their tops.
+    pub fn beside(pictures: &[Picture], gap: usize, background: Colour) -> Picture {
+        let width = pictures.iter().map(|picture| picture.width()).sum::<usize>() + gap * pictures.len().saturating_sub(1);
+        let height = pictures.iter().map(|picture| picture.height()).max().unwrap_or(0);
+        let mut combined = Picture::new(width, height, background);
+
+        let mut left = 0;
+        for picture in pictures {
+            for (pos, &colour) in picture.cells.iter() {
+                combined.fill(Pos::new(pos.x + left, pos.y), colour);
+            }
+            for stroke in &picture.strokes {
+                let points = stroke.points.iter().map(|&(x, y)| (x + left as f64, y)).collect();
+                combined.strokes.push(Stroke { points, ..stroke.clone() });
+            }
+            left += picture.width() + gap;
+        }
+        return combined;
+    }
+
+    /// The picture as an SVG, `cell_size` pixels to a cell. Runs of the same colour along a row are drawn as one rectangle.
+    pub fn to_svg(&self, cell_size: u32) -> String {
+        let mut svg = String::new();
+        let (width, height) = (self.width() as u32 * cell_size, self.height() as u32 * cell_size);
+        writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+            self.width(), self.height()).unwrap();
+
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let run = row[x..].iter().take_while(|&&colour| colour == row[x]).count();
+                writeln!(svg, r#"  <rect x="{x}" y="{y}" width="{run}" height="1" fill="{}"/>"#, row[x].to_hex()).unwrap();
+                x += run;
+            }
+        }
+
+        for stroke in &self.strokes {
+            let points: Vec<String> = stroke.points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+            writeln!(svg, r#"  <polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" shape-rendering="auto"/>"#,
+                points.join(" "), stroke.colour.to_hex(), stroke.width).unwrap();
+        }
+        svg.push_str("</svg>\n");
+        return svg;
+    }
+
+    /// The picture as RGB pixels, row by row, `cell_size` pixels to a cell.
+    pub fn to_pixels(&self, cell_size: u32) -> Grid<Colour> {
+        let cell_size = cell_size.max(1) as usize;
+        let mut pixels = Grid::from_fn(self.width() * cell_size, self.height() * cell_size, |pixel| {
+            return self.cells[Pos::new(pixel.x / cell_size, pixel.y / cell_size)];
+        });
+
+        // Each segment is drawn by stamping a square as thick as the line at every half pixel along it.
+        for stroke in &self.strokes {
+            let radius = (stroke.width * cell_size as f64 / 2.0).max(0.5);
+            for segment in stroke.points.windows(2) {
+                let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+                let length = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt() * cell_size as f64;
+                let stamps = (length * 2.0).ceil().max(1.0) as usize;
+                for stamp in 0..=stamps {
+                    let t = stamp as f64 / stamps as f64;
+                    let (x, y) = ((x0 + (x1 - x0) * t) * cell_size as f64, (y0 + (y1 - y0) * t) * cell_size as f64);
+                    let left = (x - radius).round().max(0.0) as usize;
+                    let top = (y - radius).round().max(0.0) as usize;
+                    let right = ((x + radius).round().max(0.0) as usize).min(pixels.width());
+                    let bottom = ((y + radius).round().max(0.0) as usize).min(pixels.height());
+                    for pixel_y in top..bottom {
+                        for pixel_x in left..right {
+                            pixels[Pos::new(pixel_x, pixel_y)] = stroke.colour;
+                        }
+                    }
+                }
+            }
+        }
+        return pixels;
+    }
+
+    /// The picture as a PNG, `cell_size` pixels to a cell.
+    pub fn to_png(&self, cell_size: u32) -> Vec<u8> {
+        let pixels = self.to_pixels(cell_size);
+        let mut png: Vec<u8> = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, pixels.width() as u32, pixels.height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let data: Vec<u8> = pixels.tiles().iter().flat_map(|colour| [colour.r, colour.g, colour.b]).collect();
+        // Writing into a `Vec` cannot fail.
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&data).unwrap();
+        writer.finish().unwrap();
+        return png;
+    }
+
+    /// Writes the picture to `path`: as a PNG if it ends in `.png`, and as an SVG otherwise.
+    pub fn save(&self, path: &Path, cell_size: u32) -> io::Result<()> {
+        if path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("png")) {
+            return fs::write(path, self.to_png(cell_size));
+        }
+        return fs::write(path, self.to_svg(cell_size));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_grid::Pos;
+
+    use crate::{Colour, Picture};
+
+    const RED: Colour = Colour::from_hex(0xff0000);
+
+    #[test]
+    fn test_colour() {
+        assert_eq!(Colour::from_hex(0x70c710), Colour { r: 0x70, g: 0xc7, b: 0x10 });
+        assert_eq!(Colour::from_hex(0x70c710).to_hex(), "#70c710");
+        assert_eq!(Colour::BLACK.mix(Colour::WHITE, 0.5), Colour::from_hex(0x808080));
+        assert_eq!(Colour::BLACK.mix(RED, 2.0), RED);
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let mut picture = Picture::new(3, 2, Colour::WHITE);
+        picture.fill(Pos::new(1, 1), RED);
+        picture.fill(Pos::new(2, 1), RED);
+        picture.fill(Pos::new(5, 5), RED);
+        picture.path(&[Pos::new(0, 0), Pos::new(2, 0)], Colour::BLACK, 0.25);
+
+        assert_eq!(picture.to_svg(10), r##"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="20" viewBox="0 0 3 2" shape-rendering="crispEdges">
+  <rect x="0" y="0" width="3" height="1" fill="#ffffff"/>
+  <rect x="0" y="1" width="1" height="1" fill="#ffffff"/>
+  <rect x="1" y="1" width="2" height="1" fill="#ff0000"/>
+  <polyline points="0.5,0.5 2.5,0.5" fill="none" stroke="#000000" stroke-width="0.25" stroke-linecap="round" stroke-linejoin="round" shape-rendering="auto"/>
+</svg>
+"##);
+    }
+
+    #[test]
+    fn test_to_pixels() {
+        let mut picture = Picture::new(3, 2, Colour::WHITE);
+        picture.fill(Pos::new(2, 1), RED);
+        picture.path(&[Pos::new(0, 0), Pos::new(2, 0)], Colour::BLACK, 0.5);
+
+        let pixels = picture.to_pixels(4);
+        assert_eq!((pixels.width(), pixels.height()), (12, 8));
+        assert_eq!(pixels[Pos::new(11, 7)], RED);
+        assert_eq!(pixels[Pos::new(6, 2)], Colour::BLACK);
+        assert_eq!(pixels[Pos::new(6, 0)], Colour::WHITE);
+        assert_eq!(pixels[Pos::new(0, 6)], Colour::WHITE);
+    }
+
+    #[test]
+    fn test_to_png() {
+        let picture = Picture::new(3, 2, RED);
+        let png = picture.to_png(2);
+
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height), (6, 4));
+        assert_eq!(&data[..3], &[0xff, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_beside() {
+        let mut left = Picture::new(2, 2, RED);
+        left.path(&[Pos::new(0, 0), Pos::new(1, 1)], Colour::BLACK, 0.1);
+        let mut right = Picture::new(1, 3, Colour::BLACK);
+        right.path(&[Pos::new(0, 0), Pos::new(0, 2)], RED, 0.1);
+
+        let both = Picture::beside(&[left, right], 1, Colour::WHITE);
+        assert_eq!((both.width(), both.height()), (4, 3));
+        assert_eq!(both.cells[Pos::new(1, 1)], RED);
+        assert_eq!(both.cells[Pos::new(2, 0)], Colour::WHITE);
+        assert_eq!(both.cells[Pos::new(0, 2)], Colour::WHITE);
+        assert_eq!(both.cells[Pos::new(3, 2)], Colour::BLACK);
+        assert_eq!(both.strokes[1].points, vec![(3.5, 0.5), (3.5, 2.5)]);
+    }
+}
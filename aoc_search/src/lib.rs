@@ -0,0 +1,260 @@
+//--------------------------------------------------------------------------------
+// Search: Walking graphs given by a successor function
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Breadth first search, depth first search, Dijkstra's algorithm and A* over any state that can be hashed.
+//! The graph is never built: each search asks a successor function for the neighbours of the state it is looking at.
+
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, convert::Infallible, hash::Hash, ops::Add};
+
+/// Follows the parents recorded by a search from `state` back to the start, and returns the states from the start to `state`.
+fn reconstruct_path<S>(parents: &HashMap<S, Option<S>>, state: &S) -> Vec<S> where S: Clone + Eq + Hash {
+    let mut path: Vec<S> = vec![state.clone()];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    return path;
+}
+
+/// The path with the fewest steps from `start` to a state satisfying `is_goal`, including both ends.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Vec<S>>
+where S: Clone + Eq + Hash, I: IntoIterator<Item=S> {
+    let mut parents: HashMap<S, Option<S>> = HashMap::from([(start.clone(), None)]);
+    let mut frontier: VecDeque<S> = VecDeque::from([start]);
+
+    while let Some(current) = frontier.pop_front() {
+        if is_goal(&current) {
+            return Some(reconstruct_path(&parents, &current));
+        }
+        for next in successors(&current) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(current.clone()));
+                frontier.push_back(next);
+            }
+        }
+    }
+    return None;
+}
+
+/// The number of steps from `start` to every state reachable from it.
+pub fn bfs_distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where S: Clone + Eq + Hash, I: IntoIterator<Item=S> {
+    let mut distances: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut frontier: VecDeque<S> = VecDeque::from([start]);
+
+    while let Some(current) = frontier.pop_front() {
+        let next_distance = distances[&current] + 1;
+        for next in successors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), next_distance);
+                frontier.push_back(next);
+            }
+        }
+    }
+    return distances;
+}
+
+/// The states reachable from `start` a step at a time, like a breadth first search sees them: first `start` alone,
+/// then every state one step from it, then every state two steps from it, and so on until there are none left.
+pub fn bfs_layers<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> impl Iterator<Item=Vec<S>>
+where S: Clone + Eq + Hash, I: IntoIterator<Item=S> {
+    let mut visited: HashSet<S> = HashSet::from([start.clone()]);
+    let mut layer: Vec<S> = vec![start];
+
+    return std::iter::from_fn(move || {
+        if layer.is_empty() {
+            return None;
+        }
+        let mut next_layer: Vec<S> = Vec::new();
+        for current in &layer {
+            for next in successors(current) {
+                if visited.insert(next.clone()) {
+                    next_layer.push(next);
+                }
+            }
+        }
+        return Some(std::mem::replace(&mut layer, next_layer));
+    });
+}
+
+/// Every state reachable from `start`, in the order a depth first search visits them.
+/// The successors of a state are explored starting from the last one.
+pub fn dfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Vec<S>
+where S: Clone + Eq + Hash, I: IntoIterator<Item=S> {
+    let mut visited: HashSet<S> = HashSet::new();
+    let mut order: Vec<S> = Vec::new();
+    let mut frontier: Vec<S> = vec![start];
+
+    while let Some(current) = frontier.pop() {
+        if !visited.insert(current.clone()) {
+            continue;
+        }
+        for next in successors(&current) {
+            if !visited.contains(&next) {
+                frontier.push(next);
+            }
+        }
+        order.push(current);
+    }
+    return order;
+}
+
+/// The cheapest path from `start` to a state satisfying `is_goal`, including both ends, and its cost.
+/// `successors` gives each neighbouring state with the cost of moving to it, which must not be negative.
+pub fn dijkstra<S, C, I>(start: S, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<(Vec<S>, C)>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(S, C)> {
+    return astar(start, successors, |_| C::default(), is_goal);
+}
+
+/// [`dijkstra`] with a successor function that can fail, for instance by giving up when the search is taking too long.
+/// Stops at the first error and returns it.
+pub fn try_dijkstra<S, C, I, E>(start: S, successors: impl FnMut(&S) -> Result<I, E>, is_goal: impl FnMut(&S) -> bool) -> Result<Option<(Vec<S>, C)>, E>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(S, C)> {
+    return try_astar(start, successors, |_| C::default(), is_goal);
+}
+
+/// Like [`dijkstra`], but looks at the states that `heuristic` thinks are closer to a goal first.
+/// `heuristic` must never overestimate the cost left to reach a goal, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I, heuristic: impl FnMut(&S) -> C, is_goal: impl FnMut(&S) -> bool) -> Option<(Vec<S>, C)>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(S, C)> {
+    let Ok(found) = try_astar(start, |state| Ok::<I, Infallible>(successors(state)), heuristic, is_goal);
+    return found;
+}
+
+/// [`astar`] with a successor function that can fail, like [`try_dijkstra`]. It is asked for the successors of each
+/// state the search takes off the frontier, so an error stops the search before it looks at another state.
+pub fn try_astar<S, C, I, E>(start: S, mut successors: impl FnMut(&S) -> Result<I, E>, mut heuristic: impl FnMut(&S) -> C, mut is_goal: impl FnMut(&S) -> bool) -> Result<Option<(Vec<S>, C)>, E>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(S, C)> {
+    let mut costs: HashMap<S, C> = HashMap::from([(start.clone(), C::default())]);
+    let mut parents: HashMap<S, Option<S>> = HashMap::from([(start.clone(), None)]);
+    // The heap orders states by their estimated total cost; the states themselves need not be ordered, so it stores their index in `states`.
+    let mut states: Vec<S> = vec![start.clone()];
+    let mut frontier: BinaryHeap<(Reverse<C>, Reverse<C>, Reverse<usize>)> = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(C::default()), Reverse(0))]);
+
+    while let Some((_, Reverse(current_cost), Reverse(index))) = frontier.pop() {
+        let current = states[index].clone();
+        if costs.get(&current).is_some_and(|&stored_cost| stored_cost < current_cost) {
+            continue;
+        }
+        if is_goal(&current) {
+            return Ok(Some((reconstruct_path(&parents, &current), current_cost)));
+        }
+
+        for (next, step_cost) in successors(&current)? {
+            let next_cost = current_cost + step_cost;
+            if costs.get(&next).is_some_and(|&stored_cost| stored_cost <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), Some(current.clone()));
+            frontier.push((Reverse(next_cost + heuristic(&next)), Reverse(next_cost), Reverse(states.len())));
+            states.push(next);
+        }
+    }
+    return Ok(None);
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::{astar, bfs, bfs_distances, bfs_layers, dfs, dijkstra, try_astar, try_dijkstra};
+
+    // A 5 by 5 room with a wall across the middle row, except for a gap on the right.
+    fn room_neighbours(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        return [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && !(y == 2 && x < 4))
+            .collect();
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs((0, 0), room_neighbours, |&state| state == (0, 4)).unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(0, 4)));
+        assert!(path.windows(2).all(|pair| room_neighbours(&pair[0]).contains(&pair[1])));
+
+        assert_eq!(bfs((0, 0), room_neighbours, |&state| state == (9, 9)), None);
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let distances = bfs_distances((0, 0), room_neighbours);
+        assert_eq!(distances.len(), 21);
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(4, 2)], 6);
+        assert_eq!(distances[&(0, 4)], 12);
+    }
+
+    #[test]
+    fn test_bfs_layers() {
+        let layers: Vec<Vec<(i32, i32)>> = bfs_layers((0, 0), room_neighbours).collect();
+        assert_eq!(layers.len(), 13);
+        assert_eq!(layers[0], vec![(0, 0)]);
+        assert_eq!(layers.iter().map(|layer| layer.len()).sum::<usize>(), 21);
+
+        let distances = bfs_distances((0, 0), room_neighbours);
+        for (distance, layer) in layers.iter().enumerate() {
+            assert!(layer.iter().all(|state| distances[state] == distance));
+        }
+    }
+
+    #[test]
+    fn test_dfs() {
+        // A ring of six states is visited all the way around.
+        let order = dfs(0, |&state: &i32| [(state + 1) % 6, (state + 5) % 6]);
+        assert_eq!(order, vec![0, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('a', 7), ('c', 10), ('d', 15)]),
+            ('c', vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+            ('d', vec![('b', 15), ('c', 11), ('e', 6)]),
+            ('e', vec![('d', 6), ('f', 9)]),
+            ('f', vec![('a', 14), ('c', 2), ('e', 9)]),
+        ]);
+        let successors = |state: &char| edges[state].clone();
+        assert_eq!(dijkstra('a', successors, |&state| state == 'e'), Some((vec!['a', 'c', 'f', 'e'], 20)));
+        assert_eq!(dijkstra('a', successors, |&state| state == 'a'), Some((vec!['a'], 0)));
+        assert_eq!(dijkstra('a', successors, |&state| state == 'z'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (0, 4);
+        let successors = |state: &(i32, i32)| room_neighbours(state).into_iter().map(|next| (next, 1));
+        let heuristic = |&(x, y): &(i32, i32)| x.abs_diff(goal.0) + y.abs_diff(goal.1);
+        let (path, cost) = astar((0, 0), successors, heuristic, |&state| state == goal).unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+        assert_eq!(dijkstra((0, 0), successors, |&state| state == goal).unwrap().1, 12);
+    }
+
+    #[test]
+    fn test_try_astar() {
+        let goal = (0, 4);
+        let heuristic = |&(x, y): &(i32, i32)| x.abs_diff(goal.0) + y.abs_diff(goal.1);
+        let successors = |state: &(i32, i32)| Ok::<_, ()>(room_neighbours(state).into_iter().map(|next| (next, 1)));
+        assert_eq!(try_astar((0, 0), successors, heuristic, |&state| state == goal).unwrap().unwrap().1, 12);
+        assert_eq!(try_dijkstra((0, 0), successors, |&state| state == (9, 9)), Ok(None));
+
+        // The search stops as soon as it is refused the successors of a state, here after looking at three.
+        let mut looked_at = 0;
+        let successors = |state: &(i32, i32)| {
+            looked_at += 1;
+            if looked_at > 3 {
+                return Err("gave up");
+            }
+            return Ok(room_neighbours(state).into_iter().map(|next| (next, 1)));
+        };
+        assert_eq!(try_dijkstra((0, 0), successors, |&state| state == goal), Err("gave up"));
+        assert_eq!(looked_at, 4);
+    }
+}
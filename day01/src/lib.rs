@@ -0,0 +1,209 @@
+//--------------------------------------------------------------------------------
+// Day 01: Trebuchet?!
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Recovers the calibration values hidden in each line of the trebuchet's calibration document.
+
+use std::io::Read;
+
+use aoc_common::{lines, ParseError, Solver};
+
+/// Part one: only the numeric digits of a line count.
+pub mod part_one {
+    /// Combines the first and last digit of `line` into a two-digit number, or 0 if there are none.
+    pub fn get_calibration_value(line: &str) -> u32 { 
+        let mut combined: std::string::String = String::new();
+    
+        for c in line.chars() {
+            if c.is_numeric() {
+                combined.push(c);
+                break;
+            }
+        }
+        for c in line.chars().rev() {
+            if c.is_numeric() {
+                combined.push(c);
+                break;
+            }
+        }
+    
+        if let Ok(number) = combined.parse::<u32>() {
+            return number;
+        }
+    
+        return 0;
+    }
+    
+    /// Sums the calibration value of every line in `document`.
+    pub fn sum_calibration_values(document: &str) -> u32 {
+        let mut calibration_value_sum = 0;
+        for line in document.lines() {
+            calibration_value_sum += get_calibration_value(line);
+        }
+        return calibration_value_sum;
+    }
+
+    #[cfg(test)]
+    pub fn get_cumulative_calibration_value(file_name: &str) -> Result<u32, aoc_common::ParseError> {
+        let document = aoc_common::parse_file(file_name, crate::parse_calibration_document)?;
+        return Ok(sum_calibration_values(&document));
+    }
+}
+
+/// Part two: digits may also be spelled out with letters.
+pub mod part_two {
+
+    /// Converts a spelled-out digit (`"one"`, `"two"`, ...) to its value.
+    pub fn word_to_digit(word: &str) -> std::option::Option<u32> {
+        match word {
+            "one" => return Some(1),
+            "two" => return Some(2),
+            "three" => return Some(3),
+            "four" => return Some(4),
+            "five" => return Some(5),
+            "six" => return Some(6),
+            "seven" => return Some(7),
+            "eight" => return Some(8),
+            "nine" => return Some(9),
+            "zero" => return Some(0),
+            _ => return None
+        }
+    }
+
+    /// Combines the first and last digit (numeric or spelled out) of `line` into a two-digit number.
+    pub fn get_calibration_value(line: &str) -> u32 { 
+        let word_digits: [&str; 10] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "zero"
+        ];
+
+        let mut first_digit_index: std::option::Option<usize> = None;
+        let mut first_digit: std::option::Option<u32> = None;
+
+        let mut second_digit_index: std::option::Option<usize> = None;
+        let mut second_digit: std::option::Option<u32> = None;
+
+        for word_digit in word_digits.iter() {
+            if let Some(word_digit_idx) = line.find(word_digit) {
+                if first_digit_index.is_none() || word_digit_idx < first_digit_index.unwrap() {
+                    first_digit_index = Some(word_digit_idx);
+                    first_digit = word_to_digit(word_digit);
+                }
+            }
+            if let Some(word_digit_idx) = line.rfind(word_digit) {
+                if second_digit_index.is_none() || word_digit_idx > second_digit_index.unwrap() {
+                    second_digit_index = Some(word_digit_idx);
+                    second_digit = word_to_digit(word_digit);
+                }
+            }
+        }
+
+        for (i, c) in line.chars().enumerate() {
+            if c.is_numeric() && (first_digit.is_none() || first_digit_index.unwrap() > i)  {
+                // first_digit_index = Some(i);
+                first_digit = Some(c.to_digit(10).unwrap());
+                break;
+            }
+        }
+        for (i, c) in line.chars().rev().enumerate() {
+            if c.is_numeric() && (second_digit.is_none() || second_digit_index.unwrap() < line.len() - 1 - i)  {
+                // second_digit_index = Some(i);
+                second_digit = Some(c.to_digit(10).unwrap());
+                break;
+            }
+        }
+
+
+
+        return first_digit.unwrap_or(0) * 10 + second_digit.unwrap_or(0);
+    }
+
+    /// Sums the calibration value of every line in `document`.
+    pub fn sum_calibration_values(document: &str) -> u32 {
+        let mut calibration_value_sum = 0;
+        for line in document.lines() {
+            let calibration_value: u32 = get_calibration_value(line);
+            calibration_value_sum += calibration_value;
+        }
+        return calibration_value_sum;
+    }
+
+    #[cfg(test)]
+    pub fn get_cumulative_calibration_value(file_name: &str) -> Result<u32, aoc_common::ParseError> {
+        let document = aoc_common::parse_file(file_name, crate::parse_calibration_document)?;
+        return Ok(sum_calibration_values(&document));
+    }
+}
+
+/// Checks that every line of the calibration document is made of letters and digits only.
+pub fn parse_calibration_document(input: &str) -> Result<String, ParseError> {
+    for line in lines(input) {
+        if let Some((i, character)) = line.text.char_indices().find(|(_, character)| !character.is_ascii_alphanumeric()) {
+            return Err(line.error_at(i + 1, format!("unexpected character `{character}`")));
+        }
+    }
+    return Ok(input.to_string());
+}
+
+/// Reads the calibration document from `reader`, as [`parse_calibration_document`] does.
+pub fn calibration_document_from_reader(reader: impl Read) -> Result<String, ParseError> {
+    return aoc_common::parse_reader(reader, parse_calibration_document);
+}
+
+/// Solver for day 1. The input is kept as the raw calibration document.
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_calibration_document(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return part_one::sum_calibration_values(input);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return part_two::sum_calibration_values(input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse_calibration_document;
+
+    const TEST_INPUT: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const TEST_INPUT2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn test_get_calibration_value() {
+        assert_eq!(crate::part_one::sum_calibration_values(&parse_calibration_document(TEST_INPUT).unwrap()), 142);
+        assert_eq!(crate::part_two::sum_calibration_values(&parse_calibration_document(TEST_INPUT2).unwrap()), 281);
+    }
+
+    #[test]
+    fn test_parse_calibration_document() {
+        let error = parse_calibration_document("1abc2\npqr3 stu8vwx").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}
\ No newline at end of file
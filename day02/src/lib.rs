@@ -0,0 +1,213 @@
+//--------------------------------------------------------------------------------
+// Day 02: Cube Conundrum
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Checks the games played with the Elf's bag of coloured cubes.
+
+use std::io::Read;
+
+use aoc_common::{lines, Params, ParseError, Solver};
+
+/// A game and the handfuls of cubes pulled out of the bag during it.
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    /// The number after `Game`.
+    pub id: u32,
+    /// Each handful, as the number of cubes of each colour shown.
+    pub pulls: Vec<Vec<(u32, String)>>
+}
+
+/// Reads one game per line of the puzzle input.
+pub fn parse_games(record: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games: Vec<Game> = Vec::new();
+
+    for line in lines(record) {
+        let (game_label, pulls_info) = line.split_once(line.text, ": ")?;
+        let id: u32 = match game_label.split_once(' ') {
+            Some(("Game", id_str)) => line.parse(id_str)?,
+            _ => return Err(line.error(game_label, format!("expected `Game <id>`, found `{game_label}`")))
+        };
+
+        let mut pulls: Vec<Vec<(u32, String)>> = Vec::new();
+        for pull in pulls_info.split("; ") {
+            let mut cubes: Vec<(u32, String)> = Vec::new();
+            for cube in pull.split(", ") {
+                let (cube_count, cube_color) = line.split_once(cube, " ")?;
+                cubes.push((line.parse(cube_count)?, cube_color.to_string()));
+            }
+            pulls.push(cubes);
+        }
+
+        games.push(Game { id, pulls });
+    }
+
+    return Ok(games);
+}
+
+/// Reads the games from `reader`, as [`parse_games`] does.
+pub fn games_from_reader(reader: impl Read) -> Result<Vec<Game>, ParseError> {
+    return aoc_common::parse_reader(reader, parse_games);
+}
+
+/// Part one: which games could have been played with a given bag?
+pub mod part_one {
+    use crate::Game;
+
+    /// Whether none of the game's pulls needed more cubes of a colour than `bag` holds.
+    pub fn is_game_possible(game: &Game, bag: &std::collections::HashMap<&str, u32>) -> bool {
+        for pull in &game.pulls {
+            for (cube_count, cube_color) in pull {
+                if bag.contains_key(cube_color.as_str()) && bag.get(cube_color.as_str()).unwrap() < cube_count {
+                    return false;
+                }
+            }
+        }
+
+        return true;
+    }
+
+    /// Sums the IDs of the games that are possible with `bag`.
+    pub fn sum_possible_game_ids(games: &Vec<Game>, bag: &std::collections::HashMap<&str, u32>) -> u32 {
+        let mut id_sum: u32 = 0;
+        let possible_games = games.iter().filter(|game: &&Game| is_game_possible(game, bag));
+        for game in possible_games {
+            id_sum += game.id;
+        }
+        return id_sum;
+    }
+
+    #[cfg(test)]
+    pub fn count_possible_games(file_name: &str, bag: &std::collections::HashMap<&str, u32>) -> Result<u32, aoc_common::ParseError> {
+        let games = aoc_common::parse_file(file_name, crate::parse_games)?;
+        return Ok(sum_possible_game_ids(&games, bag));
+    }
+}
+
+/// Part two: the fewest cubes of each colour that make a game possible.
+pub mod part_two {
+    use crate::Game;
+
+    /// Multiplies together the minimum number of cubes of each colour needed for the game.
+    pub fn get_cube_power(game: &Game) -> u32 {
+        let mut cube_power: u32 = 1; 
+        let mut minimum_number_of_cubes: std::collections::HashMap<&str, u32> = std::collections::HashMap::new();
+
+        for pull in &game.pulls {
+            for (cube_count, cube_color) in pull {
+                minimum_number_of_cubes.insert(cube_color,
+                     (*minimum_number_of_cubes.get(cube_color.as_str()).unwrap_or(cube_count)).max(*cube_count));
+            }
+        }
+
+        for (_, minimum) in minimum_number_of_cubes {
+            cube_power *= minimum;
+        }
+
+        return cube_power;
+    }
+
+    /// Sums the power of every game.
+    pub fn sum_game_powers(games: &Vec<Game>) -> u32 {
+        let mut sum_power: u32 = 0;
+        let game_powers = games.iter().map(get_cube_power);
+        for game_power in game_powers {
+            sum_power += game_power;
+        }
+        return sum_power;
+    }
+
+    #[cfg(test)]
+    pub fn sum_set_power(file_name: &str) -> Result<u32, aoc_common::ParseError> {
+        let games = aoc_common::parse_file(file_name, crate::parse_games)?;
+        return Ok(sum_game_powers(&games));
+    }
+}
+
+/// Solver for day 2. Part one uses the bag from the puzzle: 12 red, 13 green and 14 blue cubes, unless the params
+/// `bag.red`, `bag.green` or `bag.blue` say otherwise.
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    const PARAMS: &'static [&'static str] = &["bag.red", "bag.green", "bag.blue"];
+
+    type Input = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_games(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return Self::part_one_with(input, &Params::new());
+    }
+
+    fn part_one_with(input: &Self::Input, params: &Params) -> Self::PartOne {
+        // More cubes than fit in a `u32` make every game possible, just as `u32::MAX` of them do.
+        let cubes = |colour: &str, default: u64| params.get_or(&format!("bag.{colour}"), default).min(u32::MAX as u64) as u32;
+        let bag: std::collections::HashMap<&str, u32> = std::collections::HashMap::from([
+            ("red", cubes("red", 12)),
+            ("green", cubes("green", 13)),
+            ("blue", cubes("blue", 14))
+        ]);
+        return part_one::sum_possible_game_ids(input, &bag);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return part_two::sum_game_powers(input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Params, Solver};
+
+    use crate::parse_games;
+    use crate::part_one;
+    use crate::part_two;
+    use crate::Day02;
+
+    const TEST_INPUT: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn test_count_possible_games() {
+        let bag: std::collections::HashMap<&str, u32> = std::collections::HashMap::from([
+            ("red", 12),
+            ("green", 13),
+            ("blue", 14)
+        ]);
+        assert_eq!(part_one::sum_possible_game_ids(&parse_games(TEST_INPUT).unwrap(), &bag), 8);
+    }
+
+    #[test]
+    fn test_bag_params() {
+        let games = parse_games(TEST_INPUT).unwrap();
+        let mut params = Params::new();
+        assert_eq!(Day02::part_one_with(&games, &params), 8);
+        // With 20 red cubes game 3 becomes possible, until there are too few green cubes for it again.
+        params.set("bag.red", 20);
+        assert_eq!(Day02::part_one_with(&games, &params), 11);
+        params.set("bag.green", 5);
+        assert_eq!(Day02::part_one_with(&games, &params), 8);
+    }
+
+    #[test]
+    fn test_sum_set_pwoer() {
+        assert_eq!(part_two::sum_game_powers(&parse_games(TEST_INPUT).unwrap()), 2286);
+    } 
+
+    #[test]
+    fn test_parse_games() {
+        let error = parse_games("Game 1: 3 blue, 4 red\nGame 2: 1 blue, two green").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 17));
+    }
+}
@@ -0,0 +1,231 @@
+//--------------------------------------------------------------------------------
+// Day 03: Gear Ratios
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Finds the engine parts and gears in the gondola lift's engine schematic.
+
+use std::collections::HashMap;
+use std::option::Option;
+use std::str;
+use std::ops::Range;
+use std::io::Read;
+
+use aoc_common::{ParseError, Solver};
+use aoc_grid::{Grid, Pos};
+
+/// A `*` symbol and the part numbers adjacent to it.
+pub struct GearRatio {
+    /// How many part numbers touch the symbol. It is only a gear if there are more than one.
+    pub involved_engine_parts: u32,
+    /// The product of those part numbers.
+    pub value: u32
+}
+
+/// Reads the number spanning `number_sequence` on `row` and checks whether any symbol is adjacent to it.
+pub fn is_engine_part(row: usize, number_sequence: Range<usize>, schematic: &Grid<char>) -> (bool, u32) {
+    let mut engine_part_value: u32 = 0;
+    let mut is_part: bool = false;
+    let num_of_digits: u32 = number_sequence.len() as u32;
+
+    for (k, i) in number_sequence.enumerate() {
+        let digit_value: u32 = schematic[Pos::new(i, row)].to_digit(10).unwrap_or(0);
+        
+        // Search all adjacent spaces for a symbol
+        if !is_part {   // Skip checking if we already found out that it is a part
+            is_part = schematic.neighbours8(Pos::new(i, row)).any(|adjacent| {
+                let schematic_cell: char = schematic[adjacent];
+                return schematic_cell != '.' && !schematic_cell.is_numeric();
+            });
+        }
+        engine_part_value += digit_value * 10_u32.pow(num_of_digits - (k as u32) - 1);
+    }
+    
+    // println!("Line {} {} {} an engine part.", row + 1, engine_part_value, if is_part { "is" } else { "is not" });
+    return (is_part, engine_part_value);
+}
+
+/// Reads the schematic as a grid of ASCII characters. Every row must be as wide as the first.
+pub fn parse_engine_schematic(input: &str) -> Result<Grid<char>, ParseError> {
+    return Grid::parse_with(input, |character| {
+        if !character.is_ascii_graphic() {
+            return Err("Character is not a digit, a symbol or `.`.");
+        }
+        return Ok(character);
+    });
+}
+
+/// Reads the schematic from `reader`, as [`parse_engine_schematic`] does.
+pub fn engine_schematic_from_reader(reader: impl Read) -> Result<Grid<char>, ParseError> {
+    return aoc_common::parse_reader(reader, parse_engine_schematic);
+}
+
+/// Sums every number that is adjacent to a symbol.
+pub fn sum_schematic_engine_parts(engine_schematic: &Grid<char>) -> u32 {
+    let mut engine_part_sum: u32 = 0;
+    let mut start: Option<usize> = None;
+    let mut end: Option<usize> = None;
+    
+    for i in 0..engine_schematic.height() {
+        for j in 0..engine_schematic.width() {
+            // print!("{}", engine_schematic[Pos::new(j, i)]);
+            let schematic_cell: char = engine_schematic[Pos::new(j, i)];
+            
+            if char::is_numeric(schematic_cell) {
+                end = Some(j);
+                if start.is_none() {
+                    start = Some(j);
+                }
+            }
+
+            if (!schematic_cell.is_numeric() || j == engine_schematic.width() - 1) && start.is_some() {
+                let (is_part, value) = is_engine_part(i, start.unwrap()..end.unwrap() + 1, engine_schematic);
+                
+                if is_part {
+                    engine_part_sum += value;
+                }
+
+                start = None;
+                end = None;
+            }
+        }
+        // print!("\n");
+    }
+    // println!("{}", engine_part_sum);
+    return engine_part_sum;
+}
+
+/// Reads the number spanning `number_sequence` on `row` and returns it together with the row and column of an adjacent `*`, if any.
+pub fn is_gear_part(row: usize, number_sequence: Range<usize>, schematic: &Grid<char>) -> Option<(u32, usize, usize)> {
+    let mut engine_part_value: u32 = 0;
+    let mut gear: Option<Pos> = None;
+    let num_of_digits: u32 = number_sequence.len() as u32;
+
+    for (k, i) in number_sequence.enumerate() {
+        let digit_value: u32 = schematic[Pos::new(i, row)].to_digit(10).unwrap_or(0);
+        
+        // Search all adjacent spaces for a symbol
+        if gear.is_none() {   // Skip checking if we already found out that it is a part
+            gear = schematic.neighbours8(Pos::new(i, row)).find(|adjacent| schematic[*adjacent] == '*');
+        }
+        engine_part_value += digit_value * 10_u32.pow(num_of_digits - (k as u32) - 1);
+    }
+    
+    // println!("Line {} {} {} a gear part.", row + 1, engine_part_value, if is_part { "is" } else { "is not" });
+    if let Some(gear) = gear {
+        return Some((engine_part_value, gear.y, gear.x));
+    }
+    return None;
+}
+
+/// Groups the numbers of the schematic by the `*` they are adjacent to, keyed by `"row_column"`.
+pub fn find_gear_ratios(engine_schematic: &Grid<char>) -> HashMap<String, GearRatio> {
+    let mut gear_ratios: HashMap<String, GearRatio> = HashMap::new();
+    let mut start: Option<usize> = None;
+    let mut end: Option<usize> = None;
+    
+    for i in 0..engine_schematic.height() {
+        for j in 0..engine_schematic.width() {
+            // print!("{}", engine_schematic[Pos::new(j, i)]);
+            let schematic_cell: char = engine_schematic[Pos::new(j, i)];
+            
+            if char::is_numeric(schematic_cell) {
+                end = Some(j);
+                if start.is_none() {
+                    start = Some(j);
+                }
+            }
+
+            if (!schematic_cell.is_numeric() || j == engine_schematic.width() - 1) && start.is_some() {
+                if let Some((value, gear_row, gear_column)) = is_gear_part(i, start.unwrap()..end.unwrap() + 1, engine_schematic) {
+                    let key: String = format!("{}_{}", gear_row, gear_column);
+                    gear_ratios.entry(key)
+                        .and_modify(|gear_ratio: &mut GearRatio| {
+                            gear_ratio.involved_engine_parts += 1;
+                            gear_ratio.value *= value;
+                        })
+                        .or_insert(GearRatio { involved_engine_parts: 1, value });
+                }
+                start = None;
+                end = None;
+            }
+        }
+        // print!("\n");
+    }
+    return gear_ratios;
+}
+
+/// Sums the ratios of the `*` symbols that are adjacent to more than one number.
+pub fn sum_gear_ratios(gear_ratios: HashMap<String, GearRatio>) -> u32 {
+    let mut sum = 0;
+    for (_, v) in gear_ratios.iter() {
+        if v.involved_engine_parts > 1 {
+            sum += v.value;
+        }
+    }
+    return sum;
+}
+
+/// Solver for day 3.
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Grid<char>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_engine_schematic(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return sum_schematic_engine_parts(input);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return sum_gear_ratios(find_gear_ratios(input));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{sum_schematic_engine_parts, sum_gear_ratios, find_gear_ratios, parse_engine_schematic};
+
+    const TEST_INPUT: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    const TEST_INPUT2: &str = "\
+467..114..
+..........
+.....35...
+";
+
+    #[test]
+    fn test_sum_engine_parts() {
+        assert_eq!(sum_schematic_engine_parts(&parse_engine_schematic(TEST_INPUT).unwrap()), 4361);
+        assert_eq!(sum_schematic_engine_parts(&parse_engine_schematic(TEST_INPUT2).unwrap()), 0);
+    }
+    #[test]
+    fn test_sum_gear_ratios() {
+        assert_eq!(sum_gear_ratios(find_gear_ratios(&parse_engine_schematic(TEST_INPUT).unwrap())), 467835);
+        assert_eq!(sum_gear_ratios(find_gear_ratios(&parse_engine_schematic(TEST_INPUT2).unwrap())), 0);
+    }
+    #[test]
+    fn test_parse_engine_schematic() {
+        let error = parse_engine_schematic("467..114..\n...*......\n..35..633").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,211 @@
+
+//--------------------------------------------------------------------------------
+// Day 04: Scratchcards
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Scores the Elf's pile of scratchcards.
+
+use std::collections::HashSet;
+use std::io::Read;
+
+use aoc_common::{lines, ParseError, Solver};
+
+/// A card from the pile, along with how many copies of it were won.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Scratchcard {
+    /// The number printed after `Card`.
+    pub card_number: u8,
+    /// The numbers left of the `|`.
+    pub winning_numbers: HashSet<u64>,
+    /// The numbers right of the `|`.
+    pub owned_numbers: HashSet<u64>,
+    /// The original card plus every copy of it won from the cards before it.
+    pub quantity: u32
+}
+
+impl Scratchcard {
+    /// Counts the owned numbers that are also winning numbers.
+    pub fn count_winning_matches(&self) -> usize {
+        return self.winning_numbers.intersection(&self.owned_numbers).collect::<Vec<&u64>>().len()
+    }
+    /// One point for the first match, doubled for every match after it.
+    pub fn compute_card_value(&self) -> u64 {
+        let num_of_winning_matches = self.count_winning_matches();
+        if num_of_winning_matches < 1 {
+            return 0;
+        }
+        return 2_u64.pow(num_of_winning_matches as u32 - 1);
+    }
+}
+
+/// Adds the copies each card wins to the `quantity` of the cards below it.
+pub fn obtain_scratchcard_copies(pile: &mut Vec<Scratchcard>) {
+    for i in 0..pile.len() {
+        let num_of_winning_matches = pile[i].count_winning_matches();
+        for j in i + 1..=i + num_of_winning_matches {
+            pile[j].quantity += pile[i].quantity;
+        }
+    }
+}
+
+/// Reads the pile of scratchcards and resolves the copies they win.
+pub fn parse_scratchcards(input: &str) -> Result<Vec<Scratchcard>, ParseError> {
+    let mut scratchcards: Vec<Scratchcard> = Vec::new();
+
+    for line in lines(input) {
+        let (card_label, card_contents) = line.split_once(line.text, ": ")?;
+        let card_number: u8 = match card_label.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["Card", card_number_str] => line.parse(card_number_str)?,
+            _ => return Err(line.error(card_label, format!("expected `Card <number>`, found `{card_label}`")))
+        };
+
+        let (winning_section, owned_section) = line.split_once(card_contents, " | ")?;
+
+        let mut winning_numbers: HashSet<u64> = HashSet::new();
+        let mut owned_numbers: HashSet<u64> = HashSet::new();
+
+        for number in winning_section.split_whitespace() {
+            winning_numbers.insert(line.parse::<u64>(number)?);
+        }
+        for number in owned_section.split_whitespace() {
+            owned_numbers.insert(line.parse::<u64>(number)?);
+        }
+            
+        scratchcards.push(Scratchcard { card_number, winning_numbers, owned_numbers, quantity: 1 });
+    }
+    if scratchcards.is_empty() {
+        return Err(ParseError::end_of_input(input, "expected a scratchcard"));
+    }
+    for (i, scratchcard) in scratchcards.iter().enumerate() {
+        if i + scratchcard.count_winning_matches() >= scratchcards.len() {
+            return Err(ParseError::new(i + 1, 1, format!("card {} wins copies of cards past the end of the table", scratchcard.card_number)));
+        }
+    }
+
+    obtain_scratchcard_copies(&mut scratchcards);
+    return Ok(scratchcards);
+}
+
+/// Reads the pile of scratchcards from `reader`, as [`parse_scratchcards`] does.
+pub fn scratchcards_from_reader(reader: impl Read) -> Result<Vec<Scratchcard>, ParseError> {
+    return aoc_common::parse_reader(reader, parse_scratchcards);
+}
+
+/// Sums the point value of every card in the pile.
+pub fn compute_scratchcard_pile(pile: &Vec<Scratchcard>) -> u64 {
+    return pile.iter().map(|scratchcard: &Scratchcard| return scratchcard.compute_card_value()).sum();
+}
+
+/// Counts the cards in the pile, copies included.
+pub fn count_total_scratchcards(pile: &Vec<Scratchcard>) -> u32 {
+    return pile.iter().map(|scratchcard: &Scratchcard| return scratchcard.quantity).sum();
+}
+
+/// Solver for day 4.
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input = Vec<Scratchcard>;
+    type PartOne = u64;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_scratchcards(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return compute_scratchcard_pile(input);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return count_total_scratchcards(input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{parse_scratchcards, Scratchcard, compute_scratchcard_pile, count_total_scratchcards};
+
+    const TEST_INPUT: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn test_parse_scratchcards() {
+        assert_eq!(parse_scratchcards(TEST_INPUT).unwrap(), vec![
+            Scratchcard { 
+                card_number: 1,
+                winning_numbers: HashSet::from([41, 48, 83, 86, 17]),
+                owned_numbers: HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]),
+                quantity: 1
+             },
+            Scratchcard { 
+                card_number: 2,
+                winning_numbers: HashSet::from([13, 32, 20, 16, 61]),
+                owned_numbers: HashSet::from([61, 30, 68, 82, 17, 32, 24, 19]),
+                quantity: 2
+             },
+            Scratchcard{
+                card_number: 3,
+                winning_numbers: HashSet::from([1, 21, 53, 59, 44]),
+                owned_numbers: HashSet::from([69, 82, 63, 72, 16, 21, 14,  1]),
+                quantity: 4
+            },
+            Scratchcard{
+                card_number: 4,
+                winning_numbers: HashSet::from([41, 92, 73, 84, 69]),
+                owned_numbers: HashSet::from([59, 84, 76, 51, 58, 5, 54, 83]),
+                quantity: 8
+            },
+            Scratchcard{
+                card_number: 5,
+                winning_numbers: HashSet::from([87, 83, 26, 28, 32]),
+                owned_numbers: HashSet::from([88, 30, 70, 12, 93, 22, 82, 36]),
+                quantity: 14
+            },
+            Scratchcard{
+                card_number: 6, winning_numbers: HashSet::from([31, 18, 13, 56, 72]),
+                owned_numbers: HashSet::from([74, 77, 10, 23, 35, 67, 36, 11]),
+                quantity: 1
+            },
+        ]);
+    }
+    #[test]
+    fn test_compute_card_value() {
+        let scratchcards: Vec<Scratchcard> = parse_scratchcards(TEST_INPUT).unwrap();
+        let correct_card_values: [u64; 6] = [8, 2, 2, 1, 0, 0];
+
+        for i in 0..6 {
+            assert_eq!(scratchcards[i].compute_card_value(), correct_card_values[i]);
+        }
+    }
+
+    #[test]
+    fn test_card_pile_total() {
+        assert_eq!(compute_scratchcard_pile(&parse_scratchcards(TEST_INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_count_total_scratchcards() {
+        assert_eq!(count_total_scratchcards(&parse_scratchcards(TEST_INPUT).unwrap()), 30);
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(parse_scratchcards("").unwrap_err().message, "expected a scratchcard");
+        assert!(parse_scratchcards("\n").is_err());
+        assert_eq!(compute_scratchcard_pile(&vec![]), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,344 @@
+//--------------------------------------------------------------------------------
+// Day 05: If You Give A Seed A Fertilizer
+// Rene Jotham Culaway
+// I hate this day!!!
+//--------------------------------------------------------------------------------
+
+//! Follows seeds through the almanac's chain of maps to find the closest location to plant them in.
+
+use std::collections::BinaryHeap;
+use std::cmp::Reverse;
+use std::io::Read;
+use std::ops::Range;
+use std::str::FromStr;
+
+use aoc_common::{lines, MaybeAnswer, ParseError, Solver};
+use aoc_interval::{IntervalMap, IntervalSet};
+use tracing::{debug, trace};
+
+/// The `length` values starting at `start`, or `None` if any of them is too large for the interval crate's `i64`s.
+fn to_range(start: u64, length: u64) -> Option<Range<i64>> {
+    let end = i64::try_from(start.checked_add(length)?).ok()?;
+    return Some(i64::try_from(start).ok()?..end);
+}
+
+/// A whole map of the almanac, such as `seed-to-soil`. Values outside every range map onto themselves.
+#[derive(Debug)]
+pub struct AlmanacMap {
+    /// The name of the map, e.g. `seed-to-soil`.
+    pub map_label: String,
+    /// Shifts each source range of the map onto its destination range.
+    pub map: IntervalMap
+}
+
+impl AlmanacMap {
+    /// Adds one line of the map: `range` consecutive source values starting at `start_src` map onto the values starting at `start_dst`.
+    /// Source values that an earlier line already maps keep that mapping. Returns `false`, and adds nothing, if either
+    /// range goes beyond `i64::MAX`.
+    pub fn add_range(&mut self, start_dst: u64, start_src: u64, range: u64) -> bool {
+        let (Some(destination), Some(source)) = (to_range(start_dst, range), to_range(start_src, range)) else {
+            return false;
+        };
+        self.map.insert(source.clone(), destination.start - source.start);
+        return true;
+    }
+
+    /// Maps `key` onto its destination value.
+    pub fn convert_to_value(&self, key: u64) -> u64 {
+        return self.map.get(key as i64) as u64;
+    }
+}
+
+/// The seeds to plant and the maps from seeds all the way to locations.
+#[derive(Debug)]
+pub struct Almanac {
+    /// The numbers on the `seeds:` line. Part two reads them as pairs of a start and a length.
+    pub seeds: Vec<u64>,
+    /// The maps in the order they are applied, from `seed-to-soil` to `humidity-to-location`.
+    pub maps: Vec<AlmanacMap>,
+}
+
+impl Almanac {
+
+    /// Maps every seed to its location, closest location first.
+    pub fn get_seeds_to_locations(&self) -> BinaryHeap<Reverse<u64>> {
+        let mut seeds_to_locations: BinaryHeap<Reverse<u64>> = BinaryHeap::new();
+        
+        for seed in &self.seeds {
+            let mut current_transformation: u64 = *seed;
+            
+            for almanac_map in &self.maps {
+                current_transformation = almanac_map.convert_to_value(current_transformation);
+            }
+            trace!(seed, location = current_transformation, "mapped a seed");
+            seeds_to_locations.push(Reverse(current_transformation));
+        }
+
+        return seeds_to_locations;
+    }
+
+    /// The seeds as part two reads them: pairs of a start and a length. `None` if a seed is left over without a length,
+    /// or a range ends beyond `i64::MAX`, since part one has no use for lengths and reads such seeds just fine.
+    pub fn get_seed_ranges(&self) -> Option<IntervalSet> {
+        if self.seeds.len() % 2 == 1 {
+            debug!(seeds = self.seeds.len(), "the last seed has no length to make a range with");
+            return None;
+        }
+        return self.seeds.chunks_exact(2).map(|chunk| to_range(chunk[0], chunk[1])).collect();
+    }
+
+    /// Finds the closest location that any seed within the seed ranges maps to, by sending whole ranges of values
+    /// through the maps at once. Each map splits a range wherever one of its source ranges starts or ends.
+    /// `None` if the seeds do not make ranges (see [`Almanac::get_seed_ranges`]) or every range is empty.
+    pub fn get_minimum_location_from_seed_ranges(&self) -> Option<u64> {
+        let mut current_transformation: IntervalSet = self.get_seed_ranges()?;
+        for almanac_map in &self.maps {
+            current_transformation = almanac_map.map.map_set(&current_transformation);
+            debug!(map = almanac_map.map_label, ranges = ?current_transformation.ranges(), "mapped the seed ranges");
+        }
+
+        return current_transformation.min().map(|location| location as u64);
+    }
+
+    /// Reads an almanac from `file_name`.
+    pub fn load_almanac_from_file(file_name: &str) -> Result<Almanac, ParseError> {
+        return aoc_common::parse_file(file_name, Almanac::parse);
+    }
+
+    /// Reads an almanac from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Almanac, ParseError> {
+        return aoc_common::parse_reader(reader, Almanac::parse);
+    }
+
+    /// Reads an almanac from the puzzle input.
+    pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+
+        let mut almanac: Almanac = Almanac {
+            seeds: Vec::new(),
+            maps: Vec::new(),
+        };
+
+        let mut lines_iter = lines(input).peekable();
+        // Collect seeds
+        let Some(seeds_line) = lines_iter.next() else {
+            return Err(ParseError::end_of_input(input, "expected a line of seeds"));
+        };
+        let (_, seeds_space_separated) = seeds_line.split_once(seeds_line.text, "seeds:")?;
+        let seed_strs: Vec<&str> = seeds_space_separated.split_whitespace().collect();
+        for seed_str in &seed_strs {
+            let seed = seeds_line.parse::<u64>(seed_str)?;
+            if i64::try_from(seed).is_err() {
+                return Err(seeds_line.error(seed_str, format!("the seed is beyond {}", i64::MAX)));
+            }
+            almanac.seeds.push(seed);
+        }
+        if almanac.seeds.is_empty() {
+            return Err(seeds_line.end_error("expected at least one seed"));
+        }
+        
+        while let Some(line) = lines_iter.next() {
+            if line.text.is_empty() {
+                continue;
+            }
+            // Extract map name
+            let (map_label, _) = line.split_once(line.text, " map:")?;
+            let mut almanac_map: AlmanacMap = AlmanacMap { 
+                map_label: map_label.to_string(), 
+                map: IntervalMap::new()
+            };
+
+            // Get map values
+            while let Some(line) = lines_iter.next_if(|l| !l.text.is_empty()) {
+                let mut values = line.text.split_whitespace();
+                let mut next_value = || -> Result<u64, ParseError> {
+                    match values.next() {
+                        Some(value) => line.parse::<u64>(value),
+                        None => Err(line.end_error("expected a destination start, a source start and a length"))
+                    }
+                };
+                let (start_dst, start_src, range) = (next_value()?, next_value()?, next_value()?);
+                if !almanac_map.add_range(start_dst, start_src, range) {
+                    return Err(line.error(line.text, format!("the ranges of this line end beyond {}", i64::MAX)));
+                }
+            }
+            almanac.maps.push(almanac_map);
+        }
+        return Ok(almanac);
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Almanac::parse(input);
+    }
+}
+
+/// Solver for day 5.
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = Almanac;
+    type PartOne = u64;
+    type PartTwo = MaybeAnswer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Almanac::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return input.get_seeds_to_locations().pop().unwrap().0;
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return MaybeAnswer(input.get_minimum_location_from_seed_ranges());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BinaryHeap, cmp::Reverse};
+
+    use aoc_common::Solver;
+    use proptest::prelude::*;
+
+    use crate::{Almanac, Day05};
+
+    const TEST_INPUT: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_seed_to_location() {
+        let almanac: Almanac = Almanac::parse(TEST_INPUT).unwrap();
+        let mut seeds_to_locations = almanac.get_seeds_to_locations();
+
+        let min = seeds_to_locations.peek().unwrap().0;
+
+        let mut comparison: BinaryHeap<Reverse<u64>> = BinaryHeap::from(
+                [
+                    Reverse(82),
+                    Reverse(43),
+                    Reverse(86),
+                    Reverse(35)
+                ]
+            );
+        while !seeds_to_locations.is_empty() {
+            assert_eq!((&seeds_to_locations.pop().unwrap().0), (&comparison.pop().unwrap().0));
+        }
+        assert_eq!(min, 35_u64);
+    }
+
+    #[test]
+    fn test_seed_ranges_to_location() {
+        let almanac: Almanac = Almanac::parse(TEST_INPUT).unwrap();
+        assert_eq!(almanac.get_seed_ranges().unwrap().ranges(), &[55..68, 79..93]);
+        let seeds_to_locations = almanac.get_minimum_location_from_seed_ranges();
+
+        assert_eq!(seeds_to_locations, Some(46));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Almanac::parse("seeds: 79 9223372036854775808\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+
+        let error = Almanac::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n18446744073709551615 0 1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+
+    // Seeds that do not make ranges are fine for part one, which only looks at each seed on its own.
+    #[test]
+    fn test_seeds_without_ranges() {
+        let maps = "\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+        for (seeds, closest_location) in [("seeds: 79 14 55", 14), ("seeds: 79 9223372036854775800", 81), ("seeds: 79 0 55 0", 0)] {
+            let almanac = Almanac::parse(&format!("{seeds}\n{maps}")).unwrap();
+            assert_eq!(Day05::part_one(&almanac), closest_location);
+            assert_eq!(almanac.get_minimum_location_from_seed_ranges(), None);
+            assert_eq!(Day05::part_two(&almanac).to_string(), "none");
+        }
+    }
+
+    /// One line of a map: the destination start, the source start and the length.
+    type MapLine = (u64, u64, u64);
+
+    /// Follows `seed` through the maps one line at a time. The first line whose source range holds the value wins.
+    fn brute_force_location(seed: u64, maps: &Vec<Vec<MapLine>>) -> u64 {
+        let mut value = seed;
+        for map in maps {
+            if let Some((start_dst, start_src, _)) = map.iter().find(|(_, start_src, range)| (*start_src..start_src + range).contains(&value)) {
+                value = start_dst + (value - start_src);
+            }
+        }
+        return value;
+    }
+
+    fn almanac_text(seeds: &Vec<(u64, u64)>, maps: &Vec<Vec<MapLine>>) -> String {
+        let mut text = String::from("seeds:");
+        for (start, length) in seeds {
+            text += &format!(" {start} {length}");
+        }
+        text += "\n";
+        for (i, map) in maps.iter().enumerate() {
+            text += &format!("\nmap-{i}-to-map-{} map:\n", i + 1);
+            for (start_dst, start_src, range) in map {
+                text += &format!("{start_dst} {start_src} {range}\n");
+            }
+        }
+        return text;
+    }
+
+    proptest! {
+        #[test]
+        fn test_minimum_locations_match_brute_force(
+            seeds in prop::collection::vec((0..80u64, 1..20u64), 1..4),
+            maps in prop::collection::vec(prop::collection::vec((0..100u64, 0..100u64, 1..30u64), 0..6), 1..5)
+        ) {
+            let almanac = Almanac::parse(&almanac_text(&seeds, &maps)).unwrap();
+
+            let seed_numbers = seeds.iter().flat_map(|(start, length)| [*start, *length]);
+            let closest_location = seed_numbers.map(|seed| brute_force_location(seed, &maps)).min().unwrap();
+            prop_assert_eq!(almanac.get_seeds_to_locations().peek().unwrap().0, closest_location);
+
+            let seed_ranges = seeds.iter().flat_map(|(start, length)| *start..start + length);
+            let closest_location = seed_ranges.map(|seed| brute_force_location(seed, &maps)).min().unwrap();
+            prop_assert_eq!(almanac.get_minimum_location_from_seed_ranges(), Some(closest_location));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,261 @@
+//--------------------------------------------------------------------------------
+// Day 06: Wait For It
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Counts the ways to beat the record of each boat race by holding the button for the right amount of time.
+
+use std::iter;
+use std::ops::RangeInclusive;
+use std::io::Read;
+
+use aoc_common::{lines, Line, ParseError, Solver};
+use aoc_math::isqrt;
+use tracing::debug;
+
+/// A race and its record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaceData {
+    /// How long the race lasts, in milliseconds.
+    pub time: u64,
+    /// The record distance to beat, in millimetres.
+    pub distance: u64
+}
+
+impl RaceData {
+    /// The range of button hold times that beat the record.
+    pub fn get_winning_charge_times(&self) -> RangeInclusive<u64> {
+        // To get the winning charge times, we need to solve a system of equations.
+        // Let:
+        // t_i              be the time the toy car is charged.
+        // t_max (known)    be the race data's time. This is considered the maximum time to beat the record.
+        // d_i              the distance traveled using the charge time.
+        // d_min (known)    be the the race data's distance. This is considered the minimum distance to beat the record.
+
+        // Using the following notation, we can construct the following system:
+        // (1) t_i < t_max                  The time taken to charge must strictly be less then the maximum time.             
+        // (2) d_i = t_i(t_max - t_i)       The distance travelled is given as a function of the time taken to charge.
+        // (3) d_min < d_i                  The distance travelled should exceed the minimum distance.
+
+        // This can be simplified to the following QUADRATIC equation.
+        // d_i = -(t_i ^ 2) + t_max * t_i
+        //      or
+        // y = -x^2 + t_max * x
+        // 
+        // Note that d_i must be greater than d_min as per Eq. 3, so we include it in the final equation as well.
+        // y = -x^2 + t_max * x - d_min
+
+        // Its roots are (t_max ± sqrt(t_max^2 - 4 * d_min)) / 2. The integer square root lands within a step of the lower root,
+        // so nudge it until it is the first charge time that beats the record. The winning times are symmetric about t_max / 2.
+        let time = self.time as u128;
+        let distance = self.distance as u128;
+        let beats_record = |charge_time: u128| charge_time < time && charge_time * (time - charge_time) > distance;
+
+        let discriminant = (time * time).saturating_sub(4 * distance);
+        let mut shortest = (time - isqrt(discriminant)) / 2;
+        while shortest > 0 && beats_record(shortest - 1) {
+            shortest -= 1;
+        }
+        while shortest <= time / 2 && !beats_record(shortest) {
+            shortest += 1;
+        }
+        // If no charge time wins, `shortest` has passed the middle and the range is empty.
+        return (shortest as u64)..=(time.saturating_sub(shortest) as u64);
+    }
+
+    /// Reads the races from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Vec<RaceData>, ParseError> {
+        return aoc_common::parse_file(file_name, RaceData::parse);
+    }
+
+    /// Reads the races from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Vec<RaceData>, ParseError> {
+        return aoc_common::parse_reader(reader, RaceData::parse);
+    }
+
+    /// Reads one race per column of the puzzle input.
+    pub fn parse(input: &str) -> Result<Vec<RaceData>, ParseError> {
+        let mut races = Vec::new();
+
+        let mut race_lines = lines(input);
+        let (times_line, times_str) = read_labelled_line(input, race_lines.next(), "Time:")?;
+        let (distances_line, distances_str) = read_labelled_line(input, race_lines.next(), "Distance:")?;
+
+        let times = times_str.split_whitespace().map(|time_str: &str| times_line.parse::<u64>(time_str)).collect::<Result<Vec<u64>, ParseError>>()?;
+        let distances = distances_str.split_whitespace().map(|distance_str: &str| distances_line.parse::<u64>(distance_str)).collect::<Result<Vec<u64>, ParseError>>()?;
+        if times.len() != distances.len() {
+            return Err(distances_line.error_at(1, format!("expected {} distances, found {}", times.len(), distances.len())));
+        }
+        for (time, distance) in iter::zip(times, distances) {
+            races.push(RaceData { time, distance });
+        }
+
+        return Ok(races);
+    } 
+
+    /// Reads the single race from `file_name`, as [`RaceData::parse_ignore_spaces`] does.
+    pub fn load_from_file_ignore_spaces(file_name: &str) -> Result<RaceData, ParseError> {
+        return aoc_common::parse_file(file_name, RaceData::parse_ignore_spaces);
+    }
+
+    /// Reads the single race from `reader`, as [`RaceData::parse_ignore_spaces`] does.
+    pub fn from_reader_ignore_spaces(reader: impl Read) -> Result<RaceData, ParseError> {
+        return aoc_common::parse_reader(reader, RaceData::parse_ignore_spaces);
+    }
+
+    /// Reads the puzzle input as a single race, ignoring the spaces between the numbers of each line.
+    pub fn parse_ignore_spaces(input: &str) -> Result<RaceData, ParseError> {
+        let mut race_lines = lines(input);
+        let (times_line, times_str) = read_labelled_line(input, race_lines.next(), "Time:")?;
+        let (distances_line, distances_str) = read_labelled_line(input, race_lines.next(), "Distance:")?;
+
+        let time = parse_ignore_spaces(times_line, times_str)?;
+        let distance = parse_ignore_spaces(distances_line, distances_str)?;
+        return Ok(RaceData { time, distance });
+    } 
+}
+
+// Gets the values after `label`, which should start `line`.
+fn read_labelled_line<'a>(input: &str, line: Option<Line<'a>>, label: &str) -> Result<(Line<'a>, &'a str), ParseError> {
+    let Some(line) = line else {
+        return Err(ParseError::end_of_input(input, format!("expected a line starting with `{label}`")));
+    };
+    let Some(values) = line.text.strip_prefix(label) else {
+        return Err(line.error_at(1, format!("expected a line starting with `{label}`")));
+    };
+    return Ok((line, values));
+}
+
+// Reads the digits of `values` as one number, as if the spaces between them were not there.
+fn parse_ignore_spaces(line: Line, values: &str) -> Result<u64, ParseError> {
+    let values = values.trim_start();
+    return values.split_whitespace()
+                .collect::<String>()
+                .parse::<u64>()
+                .map_err(|error| line.error(values, format!("could not read `{values}` as one number: {error}")));
+}
+
+/// Multiplies together the number of ways to win each race.
+pub fn count_possible_ways_to_win(race_data: &Vec<RaceData>) -> u64 {
+    let mut error_margins: Vec<u64> = Vec::new();
+    for race_data in race_data {
+        let winning_charge_times: RangeInclusive<u64> = race_data.get_winning_charge_times();
+        let range_of_winning = winning_charge_times.size_hint().0 as u64;
+        debug!(time = race_data.time, distance = race_data.distance, ?winning_charge_times, ways = range_of_winning, "found the winning charge times");
+        error_margins.push(range_of_winning);
+    }
+
+    return error_margins.into_iter().reduce(|prev, curr| prev * curr).unwrap_or(0);
+}
+
+/// Solver for day 6. Part one reads the races column by column; part two reads them as one long race.
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input = (Vec<RaceData>, RaceData);
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Ok((RaceData::parse(input)?, RaceData::parse_ignore_spaces(input)?));
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return count_possible_ways_to_win(&input.0);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return count_possible_ways_to_win(&vec![input.1.clone()]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::RangeInclusive;
+    use std::iter::zip;
+
+    use proptest::prelude::*;
+
+    use crate::RaceData;
+
+    const TEST_INPUT: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn test_parse () {
+        assert_eq!(RaceData::parse(TEST_INPUT), Ok(vec![
+            RaceData { time: 7, distance: 9 },
+            RaceData { time: 15, distance: 40 },
+            RaceData { time: 30, distance: 200 }
+        ]));
+    }
+
+    #[test]
+    fn test_parse_ignore_spaces () {
+        assert_eq!(RaceData::parse_ignore_spaces(TEST_INPUT),
+            Ok(RaceData { time: 71530, distance: 940200 }),
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = RaceData::parse("Time:      7  15   30\nDistance:  9  4O  200").unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (2, 15, "could not read `4O`: invalid digit found in string"));
+
+        let error = RaceData::parse_ignore_spaces("Time:      7  15   30\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_get_winning_charge_times() {
+        let races: Vec<RaceData> = RaceData::parse(TEST_INPUT).unwrap();
+        const CORRECT_CHARGE_TIMES: [RangeInclusive<u64>; 3] = [
+            2..=5,
+            4..=11,
+            11..=19,
+        ];
+        for (race, result) in zip(races, CORRECT_CHARGE_TIMES) {
+            assert_eq!(race.get_winning_charge_times(), result);
+        }
+        assert!(RaceData { time: 4, distance: 4 }.get_winning_charge_times().is_empty());
+    }
+
+    #[test]
+    fn test_count_possible_ways_to_win() {
+        let races: Vec<RaceData> = RaceData::parse(TEST_INPUT).unwrap();
+        assert_eq!(crate::count_possible_ways_to_win(&races), 288);
+    }
+
+    #[test]
+    fn test_count_possible_ways_to_win_ignore_spaces() {
+        let races: Vec<RaceData> = vec![RaceData::parse_ignore_spaces(TEST_INPUT).unwrap()];
+        assert_eq!(crate::count_possible_ways_to_win(&races), 71503);
+    }
+
+    /// Races the boat for every possible charge time and keeps the ones that beat the record.
+    fn brute_force_winning_charge_times(race: &RaceData) -> Vec<u64> {
+        return (0..=race.time).filter(|charge_time| charge_time * (race.time - charge_time) > race.distance).collect();
+    }
+
+    proptest! {
+        #[test]
+        fn test_winning_charge_times_match_brute_force(time in 0..3000u64, distance in 0..2_300_000u64) {
+            let race = RaceData { time, distance };
+            prop_assert_eq!(race.get_winning_charge_times().collect::<Vec<u64>>(), brute_force_winning_charge_times(&race));
+        }
+
+        // Records that some charge time exactly ties are where an off-by-one in the roots would show.
+        #[test]
+        fn test_winning_charge_times_match_brute_force_near_ties(time in 0..3000u64, charge_time in 0..3000u64, offset in 0..3u64) {
+            let charge_time = charge_time.min(time);
+            let distance = (charge_time * (time - charge_time) + offset).saturating_sub(1);
+            let race = RaceData { time, distance };
+            prop_assert_eq!(race.get_winning_charge_times().collect::<Vec<u64>>(), brute_force_winning_charge_times(&race));
+        }
+    }
+}
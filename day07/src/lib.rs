@@ -0,0 +1,383 @@
+//--------------------------------------------------------------------------------
+// Day 07: Camel Cards
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Ranks hands of Camel Cards to find the total winnings of a set of bids.
+
+use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::iter::zip;
+use std::io::Read;
+
+use aoc_common::{lines, ParseError, Solver};
+
+// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+// enum CardOld {
+//     TWO, 
+//     THREE, 
+//     FOUR, 
+//     FIVE, 
+//     SIX, 
+//     SEVEN, 
+//     EIGHT, 
+//     NINE, 
+//     T, 
+//     J,
+//     Q, 
+//     K, 
+//     A
+// }
+
+/// A card label, ordered by strength when J is a joker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Card {
+    J,
+    TWO, 
+    THREE, 
+    FOUR, 
+    FIVE, 
+    SIX, 
+    SEVEN, 
+    EIGHT, 
+    NINE, 
+    T, 
+    Q, 
+    K, 
+    A
+}
+
+impl Card {
+    /// The card's strength without jokers, where a J is a plain Jack that ranks between T and Q.
+    pub fn standard_rank(&self) -> u8 {
+        match self {
+            Card::TWO => 0,
+            Card::THREE => 1,
+            Card::FOUR => 2,
+            Card::FIVE => 3,
+            Card::SIX => 4,
+            Card::SEVEN => 5,
+            Card::EIGHT => 6,
+            Card::NINE => 7,
+            Card::T => 8,
+            Card::J => 9,
+            Card::Q => 10,
+            Card::K => 11,
+            Card::A => 12,
+        }
+    }
+}
+
+impl From<Card> for u8 {
+    fn from(value: Card) -> Self {
+        return value as u8;
+    }
+}
+
+impl From<Card> for char {
+    fn from(value: Card) -> Self {
+        return value as u8 as char;
+    }
+}
+
+impl TryFrom<u8> for Card {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let card = match value {
+            b'2' => Card::TWO, 
+            b'3' => Card::THREE, 
+            b'4' => Card::FOUR, 
+            b'5' => Card::FIVE, 
+            b'6' => Card::SIX, 
+            b'7' => Card::SEVEN, 
+            b'8' => Card::EIGHT, 
+            b'9' => Card::NINE, 
+            b'T' => Card::T, 
+            b'J' => Card::J, 
+            b'Q' => Card::Q, 
+            b'K' => Card::K, 
+            b'A' => Card::A,
+            _ => return Err("Character is not a card label.")
+        };
+        return Ok(card);
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let Ok(byte) = u8::try_from(value) else {
+            return Err("Character is not a card label.");
+        };
+        return Card::try_from(byte);
+    }
+}
+
+
+/// The type of a hand, from weakest to strongest.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind
+}
+
+/// A hand of five cards and the bid placed on it. Hands are ordered the way part two ranks them, with jokers.
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
+    /// The cards in the order they were dealt.
+    pub cards: [Card; 5],
+    /// The amount won by the hand, multiplied by its rank.
+    pub bid: u16
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cards == other.cards;
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hand_type_comparison = self.get_hand_type().cmp(&other.get_hand_type());
+        // println!("{:#?} vs. {:#?}", self.get_hand_type(), &other.get_hand_type());
+        match hand_type_comparison {
+            Ordering::Equal => {    // Compare each card in both hands
+                for (card_self, card_other) in zip(self.cards, other.cards) {
+                    match card_self.cmp(&card_other) {
+                        Ordering::Equal => {},
+                        card_ordering => {
+                            // println!("{:#?} vs. {:#?} = {:#?}", card_self, card_other, card_ordering);
+                            return card_ordering;
+                        }
+                    }
+                }
+                return Ordering::Equal;
+            },
+            _ => {  // Just use hand type for comparison
+                return hand_type_comparison;
+            }
+        }
+    }
+}
+
+impl Hand {
+    /// The hand's type when each J is a joker that acts like whichever card makes the hand strongest.
+    pub fn get_hand_type(&self) -> HandType {
+        return self.get_hand_type_with_jokers(true);
+    }
+
+    /// The hand's type when J is a plain Jack.
+    pub fn get_standard_hand_type(&self) -> HandType {
+        return self.get_hand_type_with_jokers(false);
+    }
+
+    fn get_hand_type_with_jokers(&self, jokers: bool) -> HandType {
+        let mut card_frequencies: HashMap<Card, u8> = HashMap::new();
+        let mut num_of_jacks: u8 = 0;
+
+        for &card in &self.cards {
+            if !jokers || card != Card::J {
+                match card_frequencies.get(&card) {
+                    Some(frequency) => {
+                        card_frequencies.insert(card, frequency + 1);
+                    },
+                    None => {
+                        card_frequencies.insert(card, 1);
+                    }
+                }
+            } else {
+                num_of_jacks += 1;
+            }
+        }
+
+        let mut frequencies_only: Vec<u8> = card_frequencies.values().copied().collect::<Vec<u8>>();
+        frequencies_only.sort();
+        if let Some(last) = frequencies_only.last_mut() {
+            *last += num_of_jacks;
+        }
+
+        // println!("{:#?}", frequencies_only);
+
+        match frequencies_only[..] {
+            [5] | [] => HandType::FiveOfAKind,
+            [1, 4] => HandType::FourOfAKind,
+            [2, 3] => HandType::FullHouse,
+            [1, 1, 3] => HandType::ThreeOfAKind,
+            [1, 2, 2] => HandType::TwoPair,
+            [1, 1, 1, 2] => HandType::OnePair,
+            _ => HandType::HighCard
+        }
+    }
+
+    /// Orders hands the way part one does, where J is a Jack and not a joker.
+    pub fn cmp_standard(&self, other: &Self) -> Ordering {
+        return self.get_standard_hand_type().cmp(&other.get_standard_hand_type())
+                    .then_with(|| {
+                        for (card_self, card_other) in zip(self.cards, other.cards) {
+                            match card_self.standard_rank().cmp(&card_other.standard_rank()) {
+                                Ordering::Equal => {},
+                                card_ordering => {
+                                    return card_ordering;
+                                }
+                            }
+                        }
+                        return Ordering::Equal;
+                    });
+    }
+
+    /// Sums each hand's bid multiplied by its rank. `hands` must already be sorted from weakest to strongest.
+    pub fn get_total_winnings(hands: &Vec<Hand>) -> u64 {
+        return hands.iter()
+                    .map(|hand| return hand.bid as u64)
+                    .enumerate()
+                    .map(|(rank, bid)| return (rank as u64 + 1) * bid)
+                    .sum();
+    }
+
+    /// Reads the hands from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Vec<Hand>, ParseError> {
+        return aoc_common::parse_file(file_name, Hand::parse);
+    }
+
+    /// Reads the hands from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Vec<Hand>, ParseError> {
+        return aoc_common::parse_reader(reader, Hand::parse);
+    }
+
+    /// Reads one hand and bid per line of the puzzle input.
+    pub fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+        let mut hands: Vec<Hand> = vec![];
+        for line in lines(input) {
+            match line.text.split_whitespace().collect::<Vec<&str>>()[..] {
+                [cards_part, bid_part] => {
+                    let mut cards: Vec<Card> = Vec::new();
+                    for (i, byte) in cards_part.bytes().enumerate() {
+                        let card = Card::try_from(byte)
+                                        .map_err(|error| line.error_at(line.column_of(cards_part) + i, error))?;
+                        cards.push(card);
+                    }
+                    let Ok(cards) = <[Card; 5]>::try_from(cards) else {
+                        return Err(line.error(cards_part, format!("expected a hand of 5 cards, found `{cards_part}`")));
+                    };
+                    hands.push(Hand { cards, bid: line.parse(bid_part)? });
+                },
+                _ => {
+                    return Err(line.error_at(1, "expected a hand and a bid"));
+                }
+            }
+        }
+        return Ok(hands);
+    }
+}
+
+/// Solver for day 7.
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Input = Vec<Hand>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Hand::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let mut hands = input.clone();
+        hands.sort_by(Hand::cmp_standard);
+        return Hand::get_total_winnings(&hands);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let mut hands = input.clone();
+        hands.sort();
+        return Hand::get_total_winnings(&hands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Card, Hand};
+
+    fn cards(labels: &str) -> [Card; 5] {
+        let cards: Vec<Card> = labels.chars().map(|label| Card::try_from(label).unwrap()).collect();
+        return cards.try_into().unwrap();
+    }
+
+    const TEST_INPUT: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Hand::parse(TEST_INPUT), Ok(vec![
+            Hand { cards: cards("32T3K"), bid: 765 },
+            Hand { cards: cards("T55J5"), bid: 684 },
+            Hand { cards: cards("KK677"), bid: 28 },
+            Hand { cards: cards("KTJJT"), bid: 220 },
+            Hand { cards: cards("QQQJA"), bid: 483 },
+        ]))
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Hand::parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let error = Hand::parse("32T3K 765\nT55J 684").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = Hand::parse("32T3K").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    // Will only work if you use CardOld
+    // #[test]
+    // fn test_hand_ranking() {
+    //     let mut hands = Hand::parse(TEST_INPUT).unwrap();
+    //     hands.sort();
+    //     assert_eq!(hands, vec![
+    //         Hand { cards: ['3'.into(), '2'.into(), 'T'.into(), '3'.into(), 'K'.into()], bid: 765 },
+    //         Hand { cards: ['K'.into(), 'T'.into(), 'J'.into(), 'J'.into(), 'T'.into()], bid: 220 },
+    //         Hand { cards: ['K'.into(), 'K'.into(), '6'.into(), '7'.into(), '7'.into()], bid: 28 },
+    //         Hand { cards: ['T'.into(), '5'.into(), '5'.into(), 'J'.into(), '5'.into()], bid: 684 },
+    //         Hand { cards: ['Q'.into(), 'Q'.into(), 'Q'.into(), 'J'.into(), 'A'.into()], bid: 483 },
+    //     ])
+    // }
+
+    #[test]
+    fn test_standard_ranking() {
+        let mut hands = Hand::parse(TEST_INPUT).unwrap();
+        hands.sort_by(Hand::cmp_standard);
+        assert_eq!(Hand::get_total_winnings(&hands), 6440);
+    }
+
+    #[test]
+    fn get_total_winnings() {
+        let mut hands = Hand::parse(TEST_INPUT).unwrap();
+        hands.sort();
+        assert_eq!(Hand::get_total_winnings(&hands), 5905);
+    }
+
+}
+
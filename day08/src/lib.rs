@@ -0,0 +1,389 @@
+//--------------------------------------------------------------------------------
+// Day 08: Haunted Wasteland
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Follows the left/right instructions through the desert's network of nodes.
+
+use std::{collections::{HashMap, HashSet}, io::Read, str::FromStr};
+
+use aoc_common::{lines, Line, ParseError, Solver};
+use aoc_cycle::Cycle;
+use aoc_math::chinese_remainder;
+
+/// One step of the instructions on the first line of the input.
+#[derive(Debug)]
+pub enum MovementInstruction {
+    LEFT,
+    RIGHT
+}
+
+impl TryFrom<char> for MovementInstruction {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(MovementInstruction::LEFT),
+            'R' => Ok(MovementInstruction::RIGHT),
+            _ => Err("Failed to convert character into MovementInstruction.")
+        }
+    }
+}
+
+/// The network of nodes and the instructions to follow through it.
+#[derive(Debug)]
+pub struct Map {
+    /// The left and right neighbour of every node.
+    pub graph: HashMap<String, [String; 2]>,
+    /// The instructions, repeated as many times as needed.
+    pub movement_instructions: Vec<MovementInstruction>,
+    /// Where part one starts walking.
+    pub root: String
+}
+
+impl Map {
+    /// Counts the steps from `AAA` to `ZZZ`, or returns 0 if `ZZZ` cannot be reached.
+    pub fn traverse_map(&self) -> u128 {
+        let mut current_node: &String = &self.root;
+        let mut steps_traversed: u128 = 0;
+        // Standing on the same node at the same point of the instructions twice means the walk has fallen into a loop
+        // that `ZZZ` is not on.
+        let mut visited: HashSet<(&String, usize)> = HashSet::new();
+        for (instruction, movement_direction) in self.movement_instructions.iter().enumerate().cycle() {
+            if current_node == "ZZZ" { break; }
+            if !visited.insert((current_node, instruction)) {
+                return 0;
+            }
+            match self.graph.get(current_node) {
+                Some([left, right]) => {
+                    current_node = match movement_direction {
+                        MovementInstruction::LEFT => left,
+                        MovementInstruction::RIGHT => right
+                    };
+                    steps_traversed += 1;
+                },
+                None => { return 0; }
+            }
+        }
+
+        return steps_traversed;
+    }
+
+    fn is_starting_node(key: &String) -> bool {
+        return key.ends_with('A');
+    }
+
+    fn is_ending_node(key: &String) -> bool {
+        return key.ends_with('Z');
+    }
+
+    /// Every node ending in `A`, where the ghosts start.
+    pub fn get_starting_nodes(&self) -> Vec<String> {
+        return self.graph.keys().filter(|key| Map::is_starting_node(key)).cloned().collect();
+    }
+
+    /// The node reached by following `direction` from `current_node`.
+    pub fn get_next(&self, current_node: &String, direction: &MovementInstruction) -> Option<String> {
+        
+        if let Some([left, right]) = self.graph.get(current_node) {
+            return match direction {
+                MovementInstruction::LEFT => Some(left.to_string()),
+                MovementInstruction::RIGHT => Some(right.to_string())
+            }
+        }
+        
+        return None;
+    }
+
+    // Did a bit Googling for this one, and it turns out that getting the LCM is the quick solution for this one.
+    // It makes sense, especially finding out we will get to a point that a path will loop in of itself. I thought the Map was DAG!
+    // The problem itself was a bit confusing.
+    // https://www.reddit.com/r/adventofcode/comments/18df7px/comment/kcxknma/?utm_source=share&utm_medium=web2x&context=3
+    /// Counts the steps until every ghost, starting from each node ending in `A`, stands on a node ending in `Z` at once.
+    /// Each ghost's walk falls into a loop, so the steps it stands on a node ending in `Z` repeat with the period of that loop;
+    /// the answer is the first step all of those congruences agree on.
+    pub fn traverse_map_as_ghost(&self) -> u128 {
+        let ghosts: Vec<(Cycle, Vec<usize>)> = self.get_starting_nodes().iter().map(|starting_node| self.get_ghost_cycle(starting_node)).collect();
+        let Some(longest_tail) = ghosts.iter().map(|(cycle, _)| cycle.tail).max() else {
+            return 0;
+        };
+
+        // Until every ghost is in its loop, check each step on its own.
+        for steps in 1..longest_tail {
+            if ghosts.iter().all(|(cycle, ending_steps)| ending_steps.contains(&cycle.reduce(steps as u64))) {
+                return steps as u128;
+            }
+        }
+
+        // From then on, a ghost is on an ending node whenever the steps are congruent to one of the ending steps in its loop.
+        let mut congruences: Vec<(i128, i128)> = vec![(0, 1)];
+        for (cycle, ending_steps) in &ghosts {
+            let ghost_congruences: Vec<(i128, i128)> = ending_steps.iter()
+                .filter(|&&ending_step| ending_step >= cycle.tail)
+                .map(|&ending_step| (ending_step as i128, cycle.period as i128))
+                .collect();
+            congruences = congruences.iter()
+                .flat_map(|&congruence| ghost_congruences.iter().filter_map(move |&ghost_congruence| chinese_remainder([congruence, ghost_congruence])))
+                .collect();
+        }
+
+        let first_step: i128 = longest_tail.max(1) as i128;
+        let earliest = congruences.iter().map(|&(residue, modulus)| {
+            if residue >= first_step {
+                return residue;
+            }
+            return residue + (first_step - residue + modulus - 1) / modulus * modulus;
+        }).min();
+
+        return earliest.unwrap_or(0) as u128;
+    }
+
+    /// The loop that a ghost starting from `starting_node` falls into, and the steps up to the end of its first loop
+    /// on which it stands on a node ending in `Z`. A ghost's state is its node and how far along the instructions it is.
+    pub fn get_ghost_cycle(&self, starting_node: &String) -> (Cycle, Vec<usize>) {
+        let step = |(node, instruction): &(String, usize)| {
+            let next_node = self.get_next(node, &self.movement_instructions[*instruction]).unwrap();
+            return (next_node, (instruction + 1) % self.movement_instructions.len());
+        };
+        let start: (String, usize) = (starting_node.to_string(), 0);
+        let cycle = aoc_cycle::brent(&start, step);
+
+        let mut ending_steps: Vec<usize> = Vec::new();
+        let mut current = start;
+        for steps in 0..cycle.tail + cycle.period {
+            if Map::is_ending_node(&current.0) {
+                ending_steps.push(steps);
+            }
+            current = step(&current);
+        }
+        return (cycle, ending_steps);
+    }
+
+    /// Reads the map from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Map, ParseError> {
+        return aoc_common::parse_file(file_name, Map::parse);
+    }
+
+    /// Reads the map from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Map, ParseError> {
+        return aoc_common::parse_reader(reader, Map::parse);
+    }
+
+    /// Reads the instructions and the network from the puzzle input.
+    pub fn parse(input: &str) -> Result<Map, ParseError> {
+        let mut map = Map { graph: HashMap::new(), movement_instructions: Vec::new(), root: "AAA".to_string() };
+        let mut lines = lines(input);
+
+        let Some(movement_instructions_line) = lines.next() else {
+            return Err(ParseError::end_of_input(input, "expected a line of instructions"));
+        };
+
+        for (i, movement_instruction_char) in movement_instructions_line.text.chars().enumerate() {
+            let movement_instruction = MovementInstruction::try_from(movement_instruction_char)
+                                            .map_err(|error| movement_instructions_line.error_at(i + 1, error))?;
+            map.movement_instructions.push(movement_instruction);
+        }
+        if map.movement_instructions.is_empty() {
+            return Err(movement_instructions_line.end_error("expected at least one instruction"));
+        }
+
+        let mut children_lines: Vec<(Line, [&str; 2])> = vec![];
+        for line in lines.skip_while(|line| line.text.is_empty()) {
+            let (key_root, children) = line.split_once(line.text, " = ")?;
+            let Some(children) = children.strip_prefix('(').and_then(|children| children.strip_suffix(')')) else {
+                return Err(line.error(children, format!("expected `(<left>, <right>)`, found `{children}`")));
+            };
+            let (left, right) = line.split_once(children, ", ")?;
+            map.graph.insert(key_root.to_string(), [left.to_string(), right.to_string()]);
+            children_lines.push((line, [left, right]));
+        }
+
+        for (line, children) in children_lines {
+            if let Some(child) = children.iter().find(|child| !map.graph.contains_key(**child)) {
+                return Err(line.error(child, format!("there is no node `{child}`")));
+            }
+        }
+
+        return Ok(map);
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Map::parse(input);
+    }
+}
+
+/// Solver for day 8.
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = Map;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Map::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return input.traverse_map();
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return input.traverse_map_as_ghost();
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use aoc_gen::Size;
+    use proptest::prelude::*;
+
+    use crate::Map;
+
+    const TEST_INPUT: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const TEST_INPUT2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const TEST_INPUT3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    // #[test]
+    // fn test_file_load() {
+    //     let map: Map = Map::parse(TEST_INPUT).unwrap();
+    // }
+
+    #[test]
+    fn test_traversal() {
+        let map: Map = Map::parse(TEST_INPUT).unwrap();
+        assert_eq!(map.traverse_map(), 2);
+        let map: Map = Map::parse(TEST_INPUT2).unwrap();
+        assert_eq!(map.traverse_map(), 6);
+    }
+
+    #[test]
+    fn test_traversal_loops() {
+        // A loop that never reaches `ZZZ`, and a node that leads to itself one way but to `ZZZ` the other.
+        let map: Map = Map::parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(map.traverse_map(), 0);
+        let map: Map = Map::parse("LR\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(map.traverse_map(), 2);
+    }
+
+    #[test]
+    fn test_traversal_ghost() {
+        let map: Map = Map::parse(TEST_INPUT3).unwrap();
+        assert_eq!(map.traverse_map_as_ghost(), 6);
+    }
+
+    #[test]
+    fn test_traversal_ghost_offset_loops() {
+        // The first ghost reaches `11Z` on steps 1, 4, 7, ... and the second reaches `22Z` on steps 2, 4, 6, ...
+        let map: Map = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11C, 11C)\n11C = (11Z, 11Z)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)\n".parse().unwrap();
+        assert_eq!(map.traverse_map_as_ghost(), 4);
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        let map: Map = Map::from_reader(input.as_bytes()).unwrap();
+        assert_eq!(map.traverse_map(), 6);
+        let map: Map = input.parse().unwrap();
+        assert_eq!(map.traverse_map(), 6);
+    }
+
+    #[test]
+    fn test_get_starting_nodes() {
+        let map: Map = Map::parse(TEST_INPUT).unwrap();
+        assert_eq!(map.get_starting_nodes(), vec!["AAA"]);
+        let map: Map = Map::parse(TEST_INPUT2).unwrap();
+        assert_eq!(map.get_starting_nodes(), vec!["AAA"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Map::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZX)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 13));
+
+        let error = Map::parse("LUR\n\nAAA = (BBB, BBB)").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+    }
+
+    /// Walks every ghost one step at a time until they all stand on a node ending in `Z`, giving up after `limit` steps.
+    fn brute_force_ghost_steps(map: &Map, limit: u128) -> Option<u128> {
+        let mut nodes = map.get_starting_nodes();
+        let mut instructions = map.movement_instructions.iter().cycle();
+        for steps in 0..limit {
+            if nodes.iter().all(|node| node.ends_with('Z')) {
+                return Some(steps);
+            }
+            let instruction = instructions.next().unwrap();
+            nodes = nodes.iter().map(|node| map.get_next(node, instruction).unwrap()).collect();
+        }
+        return None;
+    }
+
+    proptest! {
+        #[test]
+        fn test_traversal_ghost_matches_brute_force(seed in any::<u64>(), count in 1..5usize, width in 1..6usize, height in 1..7usize) {
+            let input = aoc_gen::day08::generate(&mut aoc_gen::rng(seed), &Size { count, width, height });
+            let map = Map::parse(&input).unwrap();
+            prop_assert_eq!(Some(map.traverse_map_as_ghost()), brute_force_ghost_steps(&map, 100_000));
+        }
+
+        // Unlike the generated networks, these ghosts may pass several `Z` nodes per loop, or none at all.
+        // Up to three ghosts in 6 nodes and 3 instructions repeat together within 18 + 18 * 17 * 16 steps,
+        // so a brute force that finds nothing by then never will, and the answer is 0.
+        #[test]
+        fn test_traversal_ghost_matches_brute_force_on_any_network(
+            instructions in "[LR]{1,3}",
+            ghosts in 1..=3usize,
+            nodes in prop::collection::vec((prop::bool::ANY, 0..6usize, 0..6usize), 6)
+        ) {
+            let name = |i: usize| {
+                let ending = if i < ghosts { 'A' } else if nodes[i].0 { 'Z' } else { 'B' };
+                return format!("{i:02}{ending}");
+            };
+            let mut input = format!("{instructions}\n\n");
+            for (i, (_, left, right)) in nodes.iter().enumerate() {
+                input += &format!("{} = ({}, {})\n", name(i), name(*left), name(*right));
+            }
+            let map = Map::parse(&input).unwrap();
+            prop_assert_eq!(map.traverse_map_as_ghost(), brute_force_ghost_steps(&map, 10_000).unwrap_or(0));
+        }
+    }
+}
+
@@ -0,0 +1,150 @@
+//--------------------------------------------------------------------------------
+// Day 09: Mirage Maintenance
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Extrapolates the OASIS report's histories by taking differences until they reach zero.
+
+use std::io::Read;
+
+use aoc_common::{lines, ParseError, Solver};
+
+/// The history of a single value in the report.
+#[derive(Debug)]
+pub struct Extrapolator {
+    /// The recorded values, oldest first.
+    pub values: Vec<i64>, 
+}
+
+impl Extrapolator {
+    /// Reads the histories from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Vec<Extrapolator>, ParseError> {
+        return aoc_common::parse_file(file_name, Extrapolator::parse);
+    }
+
+    /// Reads the histories from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Vec<Extrapolator>, ParseError> {
+        return aoc_common::parse_reader(reader, Extrapolator::parse);
+    }
+
+    /// Reads one history per line of the puzzle input.
+    pub fn parse(input: &str) -> Result<Vec<Extrapolator>, ParseError> {
+        let mut extrapolators = vec![];
+
+        for line in lines(input) {
+            let values = line.text.split_ascii_whitespace().map(|number| line.parse(number)).collect::<Result<Vec<i64>, ParseError>>()?;
+            if values.is_empty() {
+                return Err(line.error_at(1, "expected at least one value"));
+            }
+            extrapolators.push(Extrapolator { values } );
+        }
+
+        return Ok(extrapolators);
+    }
+
+    /// The history followed by its differences, then the differences of those, until they are all equal.
+    pub fn compute_deltas(&self) -> Vec<Vec<i64>> {
+        let mut delta_levels: Vec<Vec<i64>> = vec![self.values.clone()];
+        
+        while let Some(last_level) = delta_levels.last() {
+            let Some(first_element) = last_level.first() else {
+                break;
+            };
+            if !last_level.iter().skip(1).any(|elem| elem != first_element) {
+                break;
+            }
+            delta_levels.push(last_level.windows(2).map(|window| {
+                match window {
+                    [left, right] => {
+                        return right - left;
+                    },
+                    _ => 0
+                }
+            }).collect());
+        }
+        
+        return delta_levels;
+    }
+
+    /// The value that would come after the last one in the history.
+    pub fn extrapolate_next(&self) -> i64 {
+        let deltas: Vec<Vec<i64>> = self.compute_deltas();
+        let next: Option<i64> = deltas.iter().map(|deltas| deltas.last().copied().unwrap_or(0)).reduce(|prev_delta, curr_delta| prev_delta + curr_delta);
+        return next.unwrap_or_default();
+    }
+
+    /// The value that would come before the first one in the history.
+    pub fn extrapolate_previous(&self) -> i64 {
+        let deltas: Vec<Vec<i64>> = self.compute_deltas();
+        let next: Option<i64> = deltas.iter()
+                                    .map(|deltas| deltas.first().copied().unwrap_or(0))
+                                    .enumerate()
+                                    .map(|(i, delta)| match i % 2 { 1 => -delta, _ => delta })
+                                    .reduce(|prev, next| prev + next);
+        return next.unwrap_or_default();
+    }
+
+}
+
+
+/// Solver for day 9.
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input = Vec<Extrapolator>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Extrapolator::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return input.iter().map(|extrapolator| return extrapolator.extrapolate_next()).reduce(|prev: i64, next: i64| prev + next).unwrap_or(0);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return input.iter().map(|extrapolator| return extrapolator.extrapolate_previous()).reduce(|prev: i64, next: i64| prev + next).unwrap_or(0);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::iter::zip;
+    use crate::Extrapolator;
+
+    const TEST_INPUT: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn test_extrapolate_next() {
+        let correct_answers: [i64; 3] = [18, 28, 68];
+        let extrapolators = Extrapolator::parse(TEST_INPUT).unwrap();
+        for (extrapolator, correct_answer) in zip(extrapolators, correct_answers) {
+            assert_eq!(extrapolator.extrapolate_next(), correct_answer);
+        }
+    }
+
+    #[test]
+    fn test_extrapolate_previous() {
+        let correct_answer: i64 = 5;
+        let extrapolators = Extrapolator::parse(TEST_INPUT).unwrap();
+        let Some(extrapolator) = extrapolators.last() else {
+            panic!();
+        };
+
+        assert_eq!(extrapolator.extrapolate_previous(), correct_answer);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Extrapolator::parse("0 3 6 9 12 15\n1 3 6 1O 15 21").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+}
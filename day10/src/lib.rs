@@ -0,0 +1,561 @@
+//--------------------------------------------------------------------------------
+// Day 10: Pipe Maze
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Walks the loop of pipes that the animal hid in and counts the tiles the loop encloses.
+
+use std::{fmt::Display, collections::{HashMap, HashSet}, io::Read, str::FromStr};
+
+use aoc_common::{ParseError, Solver};
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_math::interior_points;
+use tracing::trace;
+
+/// A tile of the sketch.
+#[derive(Clone, Copy)]
+pub enum Legend {
+    VerticalPipe,
+    HorizontalPipe,
+    BottomLeft,
+    BottomRight,
+    TopRight,
+    TopLeft,
+    Ground,
+    Start
+}
+
+impl From<Legend> for char {
+    fn from(value: Legend) -> Self {
+        match value {
+            Legend::VerticalPipe => '|',
+            Legend::HorizontalPipe => '-',
+            Legend::BottomLeft => 'L',
+            Legend::BottomRight => 'J',
+            Legend::TopRight => '7',
+            Legend::TopLeft => 'F',
+            Legend::Ground => '.',
+            Legend::Start => 'S',
+        }
+    }
+}
+
+impl TryFrom<char> for Legend {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let legend = match value {
+            '|' => Legend::VerticalPipe,
+            '-' => Legend::HorizontalPipe,
+            'L' => Legend::BottomLeft,
+            'J' => Legend::BottomRight,
+            '7' => Legend::TopRight,
+            'F' => Legend::TopLeft,
+            'S' => Legend::Start,
+            '.' => Legend::Ground,
+            _ => return Err("Character is not a Legend.")
+        };
+        return Ok(legend);
+    }
+}
+
+impl Display for Legend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+/// The sketch of the field of pipes.
+pub struct Map {
+    /// The tiles.
+    pub grid: Grid<Legend>,
+    /// Where the animal is, marked `S`.
+    pub start: Pos
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match writeln!(f, "Map ({}×{}):", self.grid.width(), self.grid.height()) {
+            Ok(_) => {},
+            Err(err) => { return Err(err) }
+        }
+        match write!(f, "{}", self.grid) {
+            Ok(_) => {},
+            Err(error) => { return Err(error); },
+        }
+        match writeln!(f, "Start: {}", self.start) {
+            Ok(_) => {},
+            Err(err) => { return Err(err) }
+        }
+        Ok(())
+    }
+}
+
+impl Map {
+    /// Reads the sketch from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Map, ParseError> {
+        return aoc_common::parse_file(file_name, Map::parse);
+    }
+
+    /// Reads the sketch from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Map, ParseError> {
+        return aoc_common::parse_reader(reader, Map::parse);
+    }
+
+    /// Reads the sketch from the puzzle input. There must be exactly one start tile.
+    pub fn parse(input: &str) -> Result<Map, ParseError> {
+        let grid: Grid<Legend> = Grid::parse(input)?;
+        let starts: Vec<Pos> = grid.iter()
+                                    .filter(|(_, cell)| matches!(cell, Legend::Start))
+                                    .map(|(position, _)| position)
+                                    .collect();
+
+        let Some(&start) = starts.first() else {
+            return Err(ParseError::end_of_input(input, "expected a start tile `S`"));
+        };
+        if let Some(second_start) = starts.get(1) {
+            return Err(ParseError::new(second_start.y + 1, second_start.x + 1, "found a second start tile `S`"));
+        }
+
+        return Ok(Map { grid, start });
+    }
+
+    fn moveable_or_none(&self, current_position: Pos, direction: Direction) -> Option<Pos> {
+        let next_position = self.grid.step(current_position, direction)?;
+
+
+
+        // Check if pipe connects
+        match direction {
+            Direction::Up => {
+                let next_cell = self.grid.get(next_position)?;
+                let current_cell = self.grid.get(current_position)?;
+
+                match (current_cell, next_cell) {
+                    (Legend::VerticalPipe, Legend::VerticalPipe) | 
+                    (Legend::VerticalPipe, Legend::TopRight) |
+                    (Legend::VerticalPipe, Legend::TopLeft) |
+                    (Legend::BottomLeft, Legend::VerticalPipe) |
+                    (Legend::BottomLeft, Legend::TopRight) |
+                    (Legend::BottomLeft, Legend::TopLeft) |
+                    (Legend::BottomRight, Legend::VerticalPipe) |
+                    (Legend::BottomRight, Legend::TopRight) |
+                    (Legend::BottomRight, Legend::TopLeft) |
+                    (Legend::Start, Legend::VerticalPipe) |
+                    (Legend::Start, Legend::TopRight) |
+                    (Legend::Start, Legend::TopLeft) => {
+                        return Some(next_position);
+                    },
+                    _ => { return None; }
+                }
+            },
+            Direction::Down => {
+                let next_cell = self.grid.get(next_position)?;
+                let current_cell = self.grid.get(current_position)?;
+
+                match (current_cell, next_cell) {
+                    (Legend::VerticalPipe, Legend::VerticalPipe) | 
+                    (Legend::VerticalPipe, Legend::BottomRight) |
+                    (Legend::VerticalPipe, Legend::BottomLeft) |
+                    (Legend::TopLeft, Legend::VerticalPipe) |
+                    (Legend::TopLeft, Legend::BottomRight) |
+                    (Legend::TopLeft, Legend::BottomLeft) |
+                    (Legend::TopRight, Legend::VerticalPipe) |
+                    (Legend::TopRight, Legend::BottomRight) |
+                    (Legend::TopRight, Legend::BottomLeft) |
+                    (Legend::Start, Legend::VerticalPipe) |
+                    (Legend::Start, Legend::BottomRight) |
+                    (Legend::Start, Legend::BottomLeft) => {
+                        return Some(next_position);
+                    },
+                    _ => { return None; }
+                }
+            },
+            Direction::Left => {
+                let next_cell = self.grid.get(next_position)?;
+                let current_cell = self.grid.get(current_position)?;
+
+                match (current_cell, next_cell) {
+                    (Legend::HorizontalPipe, Legend::HorizontalPipe) |
+                    (Legend::HorizontalPipe, Legend::BottomLeft) |
+                    (Legend::HorizontalPipe, Legend::TopLeft) |
+                    (Legend::BottomRight, Legend::HorizontalPipe) |
+                    (Legend::BottomRight, Legend::BottomLeft) |
+                    (Legend::BottomRight, Legend::TopLeft) |
+                    (Legend::TopRight, Legend::HorizontalPipe) |
+                    (Legend::TopRight, Legend::BottomLeft) |
+                    (Legend::TopRight, Legend::TopLeft) |
+                    (Legend::Start, Legend::BottomLeft) |
+                    (Legend::Start, Legend::TopLeft) |
+                    (Legend::Start, Legend::HorizontalPipe) => {
+                        return Some(next_position);
+                    },
+                    _ => { return None; }
+                }
+            },
+            Direction::Right => {
+                let next_cell = self.grid.get(next_position)?;
+                let current_cell = self.grid.get(current_position)?;
+
+                match (current_cell, next_cell) {
+                    (Legend::HorizontalPipe, Legend::HorizontalPipe) |
+                    (Legend::HorizontalPipe, Legend::BottomRight) |
+                    (Legend::HorizontalPipe, Legend::TopRight) |
+                    (Legend::BottomLeft, Legend::HorizontalPipe) |
+                    (Legend::BottomLeft, Legend::BottomRight) |
+                    (Legend::BottomLeft, Legend::TopRight) |
+                    (Legend::TopLeft, Legend::HorizontalPipe) |
+                    (Legend::TopLeft, Legend::BottomRight) |
+                    (Legend::TopLeft, Legend::TopRight) |
+                    (Legend::Start, Legend::BottomRight) |
+                    (Legend::Start, Legend::TopRight) |
+                    (Legend::Start, Legend::HorizontalPipe) => {
+                        return Some(next_position);
+                    },
+                    _ => { return None; }
+                }
+            },
+        }
+    }
+
+    /// The tiles connected to `current` by a pipe on both ends.
+    fn connected_tiles(&self, current: Pos) -> Vec<Pos> {
+        let possible_directions: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+        return possible_directions.iter().filter_map(| direction | self.moveable_or_none(current, *direction)).collect();
+    }
+
+    /**
+     * Performs breadth first search. Gets the distance of each vertex to the source.
+     */
+    pub fn bfs_distance(&self) -> HashMap<Pos, usize> {
+        return aoc_search::bfs_distances(self.start, |current| self.connected_tiles(*current));
+    }
+
+    /**
+     * Performs breadth first search one step at a time. Gets the frontier after each step: first the start tile, then
+     * the two tiles next to it on the loop, and so on until both ways meet on the far side.
+     */
+    pub fn bfs_frontiers(&self) -> impl Iterator<Item = Vec<Pos>> + '_ {
+        return aoc_search::bfs_layers(self.start, |current| self.connected_tiles(*current));
+    }
+
+    /**
+     * Performs depth first search. Gets every tile of the loop, in the order they are visited going around it.
+     */
+    pub fn dfs_ordered_by_traversal(&self) -> Vec<Pos> {
+        return aoc_search::dfs(self.start, |current| self.connected_tiles(*current));
+    }
+
+    // fn print_bfs_by_distance(&self, visited_distance: &HashMap<Pos, u32>) {
+    //     for i in 0..self.height {
+    //         for j in 0..self.width {
+    //             match visited_distance.get(&(j, i)) {
+    //                 None => print!(".\t"),
+    //                 Some(distance) => print!("{distance}\t"),
+    //             }
+    //         }
+    //         println!("");
+    //     }
+    // }
+
+    /**
+     * Counts the tiles enclosed by the loop.
+     */
+    pub fn get_interior_area(&self) -> i128 {
+        let vertices = self.dfs_ordered_by_traversal();
+        // Every tile of the loop is a vertex, so the interior tiles are the lattice points inside the polygon (Pick's theorem).
+        let vertices: Vec<(i128, i128)> = vertices.iter().map(|vertex| (vertex.x as i128, vertex.y as i128)).collect();
+        trace!(?vertices, "walked the loop");
+
+        return interior_points(&vertices);
+    }
+
+    /**
+     * Gets the tiles enclosed by the loop, row by row. A tile is enclosed if the loop crosses its row an odd number of
+     * times to its left, counting only the tiles of the loop with a pipe going up.
+     */
+    pub fn interior_tiles(&self) -> Vec<Pos> {
+        let loop_tiles = self.dfs_ordered_by_traversal();
+        let on_loop: HashSet<Pos> = loop_tiles.iter().copied().collect();
+        // The start tile has a pipe going up if the loop leaves or returns through the tile above it.
+        let above_start = self.grid.step(self.start, Direction::Up);
+        let start_goes_up = above_start.is_some() && (loop_tiles.get(1) == above_start.as_ref() || loop_tiles.last() == above_start.as_ref());
+
+        let mut interior: Vec<Pos> = Vec::new();
+        for y in 0..self.grid.height() {
+            let mut inside = false;
+            for x in 0..self.grid.width() {
+                let position = Pos::new(x, y);
+                if !on_loop.contains(&position) {
+                    if inside {
+                        interior.push(position);
+                    }
+                    continue;
+                }
+                let goes_up = match self.grid[position] {
+                    Legend::VerticalPipe | Legend::BottomLeft | Legend::BottomRight => true,
+                    Legend::Start => start_goes_up,
+                    _ => false
+                };
+                if goes_up {
+                    inside = !inside;
+                }
+            }
+        }
+        return interior;
+    }
+
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Map::parse(input);
+    }
+}
+
+/// Solver for day 10.
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Map::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return *input.bfs_distance().values().max().unwrap();
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return input.get_interior_area();
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use aoc_gen::Size;
+    use aoc_grid::Pos;
+    use proptest::prelude::*;
+
+    use crate::Map;
+
+    const TEST_INPUT: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+";
+
+    const TEST_INPUT2: &str = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+";
+
+    const TEST_INPUT3: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    #[test]
+    fn test_bfs_1() {
+        let Ok(map) = Map::parse(TEST_INPUT) else {
+            panic!();
+        };
+        let distances = map.bfs_distance();
+        // map.print_bfs_by_distance(&distances);
+        assert_eq!(*distances.values().max().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_bfs_2() {
+        let Ok(map) = Map::parse(TEST_INPUT2) else {
+            panic!();
+        };
+        // let distances = map.bfs_distance();
+        // map.print_bfs_by_distance(&distances);
+        assert_eq!(*map.bfs_distance().values().max().unwrap(), 8);
+    }
+
+    #[test]
+    fn test_bfs_frontiers() {
+        let Ok(map) = Map::parse(TEST_INPUT) else {
+            panic!();
+        };
+        let frontiers: Vec<Vec<Pos>> = map.bfs_frontiers().collect();
+        assert_eq!(frontiers[0], vec![map.start]);
+        assert_eq!(frontiers.len() - 1, *map.bfs_distance().values().max().unwrap());
+        assert_eq!(frontiers.last().unwrap(), &vec![Pos::new(3, 3)]);
+    }
+
+    #[test]
+    fn test_dfs_ordered_by_traversal() {
+        let Ok(map) = Map::parse(TEST_INPUT) else {
+            panic!();
+        };
+        let tiles = map.dfs_ordered_by_traversal();
+        assert_eq!(tiles.len(), 2 * *map.bfs_distance().values().max().unwrap());
+        assert_eq!(tiles[0], map.start);
+        for pair in tiles.windows(2) {
+            assert_eq!(pair[0].manhattan_distance(pair[1]), 1);
+        }
+    }
+
+    #[test]
+    fn test_area() {
+        let Ok(map) = Map::parse(TEST_INPUT3) else {
+            panic!();
+        };
+        // let distances = map.bfs_loop();
+        // map.print_bfs_by_distance(&distances);
+        assert_eq!(map.get_interior_area(), 4);
+    }
+
+    #[test]
+    fn test_interior_tiles() {
+        let Ok(map) = Map::parse(TEST_INPUT3) else {
+            panic!();
+        };
+        assert_eq!(map.interior_tiles(), vec![Pos::new(2, 6), Pos::new(3, 6), Pos::new(7, 6), Pos::new(8, 6)]);
+
+        let Ok(map) = Map::parse(TEST_INPUT2) else {
+            panic!();
+        };
+        assert_eq!(map.interior_tiles().len() as i128, map.get_interior_area());
+    }
+
+    #[test]
+    fn test_area2() {
+        let Ok(map) = Map::parse(TEST_INPUT) else {
+            panic!();
+        };
+        // let distances = map.bfs_loop();
+        // map.print_bfs_by_distance(&distances);
+        assert_eq!(map.get_interior_area(), 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Map::parse(".S-7.\n.|.|.\n.L-S.").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 4));
+
+        let error = Map::parse(".F-7.\n.|.|.\n.L-J.").err().unwrap();
+        assert_eq!((error.line, error.column), (4, 1));
+
+        let error = Map::parse(".S-7.\n.|x|.\n.L-J.").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    const UP: (isize, isize) = (0, -1);
+    const DOWN: (isize, isize) = (0, 1);
+    const LEFT: (isize, isize) = (-1, 0);
+    const RIGHT: (isize, isize) = (1, 0);
+
+    /// The directions a tile's pipe leads in.
+    fn openings(tile: char) -> Vec<(isize, isize)> {
+        return match tile {
+            '|' => vec![UP, DOWN],
+            '-' => vec![LEFT, RIGHT],
+            'L' => vec![UP, RIGHT],
+            'J' => vec![UP, LEFT],
+            '7' => vec![DOWN, LEFT],
+            'F' => vec![DOWN, RIGHT],
+            _ => vec![]
+        };
+    }
+
+    /// Follows the pipes out of `S` and back, tile by tile. Gives the tiles of the loop in order, and `S` replaced by
+    /// the pipe that joins its two neighbours in the loop.
+    fn brute_force_loop(mut tiles: Vec<Vec<char>>) -> (Vec<(usize, usize)>, Vec<Vec<char>>) {
+        let tile_at = |tiles: &Vec<Vec<char>>, (x, y): (usize, usize), (dx, dy): (isize, isize)| {
+            let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            let tile = *tiles.get(y)?.get(x)?;
+            return Some(((x, y), tile));
+        };
+        let start = tiles.iter().enumerate()
+            .find_map(|(y, row)| row.iter().position(|&tile| tile == 'S').map(|x| (x, y)))
+            .unwrap();
+        let first_direction = [UP, RIGHT, DOWN, LEFT].into_iter()
+            .find(|&(dx, dy)| tile_at(&tiles, start, (dx, dy)).is_some_and(|(_, tile)| openings(tile).contains(&(-dx, -dy))))
+            .unwrap();
+
+        let mut loop_tiles = vec![start];
+        let mut direction = first_direction;
+        let (mut position, mut tile) = tile_at(&tiles, start, direction).unwrap();
+        while tile != 'S' {
+            loop_tiles.push(position);
+            direction = openings(tile).into_iter().find(|&(dx, dy)| (-dx, -dy) != direction).unwrap();
+            (position, tile) = tile_at(&tiles, position, direction).unwrap();
+        }
+
+        let start_openings = [first_direction, (-direction.0, -direction.1)];
+        let start_tile = "|-LJ7F".chars().find(|&tile| start_openings.iter().all(|opening| openings(tile).contains(opening))).unwrap();
+        tiles[start.1][start.0] = start_tile;
+        return (loop_tiles, tiles);
+    }
+
+    /// Counts the tiles enclosed by the loop by scanning each row from the left: a tile is inside if the loop has crossed
+    /// the row an odd number of times before it. The loop crosses the row at each of its tiles with a pipe leading up.
+    fn brute_force_interior_area(loop_tiles: &Vec<(usize, usize)>, tiles: &Vec<Vec<char>>) -> i128 {
+        let mut interior_area = 0;
+        for (y, row) in tiles.iter().enumerate() {
+            let mut inside = false;
+            for (x, &tile) in row.iter().enumerate() {
+                if loop_tiles.contains(&(x, y)) {
+                    inside ^= openings(tile).contains(&UP);
+                } else if inside {
+                    interior_area += 1;
+                }
+            }
+        }
+        return interior_area;
+    }
+
+    /// `tiles` transposed and/or flipped upside down, with the pipes turned to match.
+    fn reorient(tiles: Vec<Vec<char>>, transpose: bool, flip: bool) -> Vec<Vec<char>> {
+        let mut tiles = tiles;
+        if transpose {
+            let turn = |tile: char| match tile { '|' => '-', '-' => '|', 'L' => '7', '7' => 'L', other => other };
+            tiles = (0..tiles[0].len()).map(|x| tiles.iter().map(|row| turn(row[x])).collect()).collect();
+        }
+        if flip {
+            let turn = |tile: char| match tile { 'L' => 'F', 'F' => 'L', 'J' => '7', '7' => 'J', other => other };
+            tiles = tiles.into_iter().rev().map(|row| row.into_iter().map(turn).collect()).collect();
+        }
+        return tiles;
+    }
+
+    proptest! {
+        #[test]
+        fn test_loop_matches_brute_force(seed in any::<u64>(), width in 2..12usize, height in 2..12usize, transpose in any::<bool>(), flip in any::<bool>()) {
+            let input = aoc_gen::day10::generate(&mut aoc_gen::rng(seed), &Size { count: 1, width, height });
+            let tiles = reorient(input.lines().map(|line| line.chars().collect()).collect(), transpose, flip);
+            let input: String = tiles.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+            let map = Map::parse(&input).unwrap();
+
+            let (loop_tiles, tiles) = brute_force_loop(tiles);
+            prop_assert_eq!(*map.bfs_distance().values().max().unwrap(), loop_tiles.len() / 2);
+            prop_assert_eq!(map.get_interior_area(), brute_force_interior_area(&loop_tiles, &tiles));
+            prop_assert_eq!(map.interior_tiles().len() as i128, map.get_interior_area());
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//--------------------------------------------------------------------------------
+// Day 11: Cosmic Expansion
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Measures the distances between the galaxies in the observatory's image after the universe expands.
+
+use std::collections::{BTreeSet, HashSet};
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_common::{Params, ParseError, Solver};
+use aoc_grid::Grid;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A pixel of the image.
+#[derive(Debug)]
+pub enum Legend {
+    Galaxy,
+    Empty,
+}
+
+impl TryFrom<char> for Legend {
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Legend::Galaxy),
+            '.' => Ok(Legend::Empty),
+            _ => Err("Character is not a Legend."),
+        }
+    }
+
+    type Error = &'static str;
+}
+
+impl From<Legend> for char {
+    fn from(value: Legend) -> Self {
+        match value {
+            Legend::Galaxy => '#',
+            Legend::Empty => '.',
+        }
+    }
+}
+
+/// The galaxies of the image and the rows and columns that expand. Positions are `(x, y)` before expansion.
+#[derive(Debug)]
+pub struct Map {
+    // grid: Vec<Vec<Legend>>,
+    /// The rows without any galaxy.
+    pub empty_y: BTreeSet<u64>,
+    /// The columns without any galaxy.
+    pub empty_x: BTreeSet<u64>,
+    /// Every galaxy, in reading order.
+    pub galaxies: Vec<(u64, u64)>
+}
+
+/// The length of the shortest path between `a` and `b` moving only up, down, left or right.
+pub fn manhattan_distance(a: (u64, u64), b: (u64, u64)) -> u64 {
+    return u64::abs_diff(a.0, b.0) + u64::abs_diff(a.1, b.1);
+}
+
+impl Map {
+
+    /// Where `pos` ends up once every empty row and column has grown to `expansion_level` (2 by default) times its size.
+    pub fn get_expanded_coordinate(&self, pos: (u64, u64), expansion_level: Option<u64>) -> (u64, u64) {
+        let x_range = 0..pos.0;
+        let y_range = 0..pos.1;
+
+        let num_of_col_expansions = self.empty_x.range(x_range).count() as u64;
+        let num_of_row_expansions = self.empty_y.range(y_range).count() as u64;
+
+        // println!("expansion: {}, {}", num_of_col_expansions, num_of_row_expansions);
+        return (pos.0 + num_of_col_expansions * (expansion_level.unwrap_or(2) - 1), pos.1 + num_of_row_expansions * (expansion_level.unwrap_or(2) - 1));
+    }
+
+    /// Every pair of galaxy indices, smallest index first.
+    pub fn enumerate_combinations(&self) -> HashSet<(u64, u64)> {
+        let num_of_galaxies = self.galaxies.len() as u64;
+        let mut combinations: HashSet<(u64, u64)> = HashSet::new();
+
+        for i in 0..num_of_galaxies {
+            for j in 0..num_of_galaxies {
+                if i == j { // A galaxy is not paired with itself
+                    continue;
+                }
+                combinations.insert((i.min(j), i.max(j)));
+            }
+        }
+
+        return combinations;
+    }
+
+    /// The distance between the pair of galaxies after expansion, or `None` if either index is out of range.
+    pub fn compute_galaxy_distance(&self, pair: (u64, u64), expansion_level: Option<u64>) -> Option<u64> {
+        let a = *self.galaxies.get(pair.0 as usize)?;
+        let b = *self.galaxies.get(pair.1 as usize)?;
+        
+        let expanded_a = self.get_expanded_coordinate(a, expansion_level);
+        let expanded_b = self.get_expanded_coordinate(b, expansion_level);
+        
+        Some(manhattan_distance(expanded_a, expanded_b))
+    }
+
+    /// Sums the distance between every pair of galaxies when empty space doubles.
+    pub fn compute_sum_galaxy_pairs (&self) -> u64 {
+        return self.sum_galaxy_pairs(2);
+    }
+
+    /// Sums the distance between every pair of galaxies when empty space grows a million times larger.
+    pub fn compute_sum_galaxy_pairs_million (&self) -> u64 {
+        return self.sum_galaxy_pairs(1_000_000);
+    }
+
+    /// Sums the distance between every pair of galaxies when each empty row and column grows `expansion_level` times larger.
+    /// With the `parallel` feature, the pairs are shared out between threads.
+    pub fn sum_galaxy_pairs(&self, expansion_level: u64) -> u64 {
+        let pairs = self.enumerate_combinations();
+        #[cfg(feature = "parallel")]
+        let pairs = pairs.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let pairs = pairs.into_iter();
+        return pairs.map(|pair| self.compute_galaxy_distance(pair, Some(expansion_level)).unwrap_or(0)).sum();
+    }
+
+    /// Reads the image from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Map, ParseError> {
+        return aoc_common::parse_file(file_name, Map::parse);
+    }
+
+    /// Reads the image from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Map, ParseError> {
+        return aoc_common::parse_reader(reader, Map::parse);
+    }
+
+    /// Reads the image from the puzzle input.
+    pub fn parse(input: &str) -> Result<Map, ParseError> {
+        let grid: Grid<Legend> = Grid::parse(input)?;
+        let is_empty = |legend: &Legend| matches!(legend, Legend::Empty);
+
+        let empty_y: BTreeSet<u64> = grid.rows()
+                                        .enumerate()
+                                        .filter(|(_, row)| row.iter().all(is_empty))
+                                        .map(|(i, _)| i as u64)
+                                        .collect();
+        let empty_x: BTreeSet<u64> = (0..grid.width())
+                                        .filter(|&j| grid.column(j).all(is_empty))
+                                        .map(|j| j as u64)
+                                        .collect();
+        let galaxies: Vec<(u64, u64)> = grid.iter()
+                                        .filter(|(_, legend)| matches!(legend, Legend::Galaxy))
+                                        .map(|(position, _)| (position.x as u64, position.y as u64))
+                                        .collect();
+
+        return Ok(Map { empty_y, empty_x, galaxies });
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Map::parse(input);
+    }
+}
+
+/// Solver for day 11. Empty space grows a million times larger in part two, unless the param `expansion` says otherwise.
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    const PARAMS: &'static [&'static str] = &["expansion"];
+
+    type Input = Map;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Map::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return input.compute_sum_galaxy_pairs();
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return input.compute_sum_galaxy_pairs_million();
+    }
+
+    fn part_two_with(input: &Self::Input, params: &Params) -> Self::PartTwo {
+        return input.sum_galaxy_pairs(params.get_or("expansion", 1_000_000));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Params, Solver};
+
+    use crate::{Day11, Map};
+
+    const TEST_INPUT: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn test_combinations() {
+        let map = Map::parse(TEST_INPUT).unwrap();
+        assert_eq!(map.enumerate_combinations().len(), 36);
+    }
+
+    #[test]
+    fn test_galaxy_distance() {
+        let map = Map::parse(TEST_INPUT).unwrap();
+        let input_output: [((u64, u64), u64); 4] = [((5, 9), 9), ((1, 7), 15), ((3, 6), 17), ((8, 9), 5)];
+
+        for ((a, b), expected_result) in input_output {
+            assert_eq!(map.compute_galaxy_distance((a - 1, b - 1), Some(2)), Some(expected_result));
+        }
+    }
+
+    #[test]
+    fn test_sum_galaxy_pairs() {
+        let map = Map::parse(TEST_INPUT).unwrap();
+        println!("{:?}", map.enumerate_combinations());
+        assert_eq!(map.compute_sum_galaxy_pairs(), 374);
+    }
+
+    #[test]
+    fn test_expansion_param() {
+        let map = Map::parse(TEST_INPUT).unwrap();
+        let mut params = Params::new();
+        assert_eq!(Day11::part_two_with(&map, &params), Day11::part_two(&map));
+        params.set("expansion", 10);
+        assert_eq!(Day11::part_two_with(&map, &params), 1030);
+        params.set("expansion", 100);
+        assert_eq!(Day11::part_two_with(&map, &params), 8410);
+    }
+
+    // With or without the `parallel` feature, the sum is the same as adding up the pairs one at a time.
+    #[test]
+    fn test_sum_galaxy_pairs_generated() {
+        for seed in 0..20 {
+            let input = aoc_gen::generate(11, seed, &aoc_gen::Size { count: 40, width: 30, height: 30 }).unwrap();
+            let map = Map::parse(&input).unwrap();
+            for expansion_level in [2, 10, 1_000_000] {
+                let one_at_a_time: u64 = map.enumerate_combinations().into_iter().map(|pair| map.compute_galaxy_distance(pair, Some(expansion_level)).unwrap()).sum();
+                assert_eq!(map.sum_galaxy_pairs(expansion_level), one_at_a_time);
+            }
+        }
+    }
+}
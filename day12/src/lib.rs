@@ -0,0 +1,454 @@
+//--------------------------------------------------------------------------------
+// Day 12: Hot Springs
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Counts the arrangements of operational and damaged springs that fit each row's damaged records.
+
+use std::{fmt::Display, collections::BTreeMap, io::Read};
+
+use aoc_common::{lines, Cancellation, Cancelled, Params, ParseError, Solver};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::trace;
+
+/// The recorded condition of a spring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Unknown,
+    Functional,
+    Broken    
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+impl From<Condition> for char {
+    fn from(value: Condition) -> Self {
+        match value {
+            Condition::Unknown => '?',
+            Condition::Functional => '.',
+            Condition::Broken => '#',
+        }
+    }
+}
+
+impl TryFrom<char> for Condition {
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '?' => Ok(Condition::Unknown),
+            '.' => Ok(Condition::Functional),
+            '#' => Ok(Condition::Broken),
+            _ => Err("No Condition equivalent for character.")
+        }
+    }
+
+    type Error = &'static str;
+}
+
+/// A row of springs and the sizes of its contiguous groups of damaged springs.
+#[derive(Debug)]
+pub struct ConditionRecord {
+    /// The condition of each spring, some of them unknown.
+    pub condition_symbols: Vec<Condition>,
+    /// The size of each group of damaged springs, in order.
+    pub group_sizes: Vec<u64>
+}
+
+impl ConditionRecord {
+    /// Reads the records from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Vec<ConditionRecord>, ParseError> {
+        return aoc_common::parse_file(file_name, ConditionRecord::parse);
+    }
+
+    /// Reads the records from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Vec<ConditionRecord>, ParseError> {
+        return aoc_common::parse_reader(reader, ConditionRecord::parse);
+    }
+
+    /// Reads one record per line of the puzzle input.
+    pub fn parse(input: &str) -> Result<Vec<ConditionRecord>, ParseError> {
+        let mut condition_records: Vec<ConditionRecord> = vec![];
+
+        for line in lines(input) {
+            match line.text.split_whitespace().collect::<Vec<&str>>()[..] {
+                [broken_map_string, group_sizes_string] => {
+                    let mut condition_symbols: Vec<Condition> = Vec::new();
+                    for (i, character) in broken_map_string.char_indices() {
+                        let condition = Condition::try_from(character)
+                                            .map_err(|error| line.error_at(line.column_of(broken_map_string) + i, error))?;
+                        condition_symbols.push(condition);
+                    }
+
+                    let group_sizes: Vec<u64> = group_sizes_string.split(",")
+                                                    .map(|group_size_string| line.parse(group_size_string))
+                                                    .collect::<Result<Vec<u64>, ParseError>>()?;
+
+                    condition_records.push(
+                        ConditionRecord { condition_symbols, group_sizes }
+                    )
+                }
+                _ => {
+                    return Err(line.error_at(1, "expected the conditions of the springs and the sizes of their groups"));
+                }
+            }
+        }
+
+        Ok(condition_records)
+    }
+
+    /// Repeats the springs five times, joined by unknown springs, and the group sizes five times.
+    pub fn unfold (&self) -> Self {
+        let mut condition_symbols = [&self.condition_symbols[..], &[Condition::Unknown]].concat().repeat(5);
+        condition_symbols.pop();
+        ConditionRecord {
+            condition_symbols,
+            group_sizes: self.group_sizes.repeat(5)
+        }
+    }
+
+    // pub fn condition_symbols_to_string(&self) -> String {
+    //     return String::from_utf8(
+    //         self.condition_symbols.iter()
+    //                 .map(|condition| char::from(*condition) as u8)
+    //                 .collect::<Vec<u8>>()
+    //     ).unwrap()
+    // }
+
+    fn generate_linear_nfa_from_groups(&self) -> Vec<Condition> {
+        // Generate an NFA representing the desired groupings
+        // The last two states (# or .) are the accepting states.
+        let mut nfa: Vec<Condition> = vec![Condition::Functional];
+        for group_size in self.group_sizes.iter() {
+            for _ in 0..*group_size {
+                nfa.push(Condition::Broken);
+            }
+            nfa.push(Condition::Functional);
+        }
+
+        // println!("{:?}", nfa);
+        
+        return nfa;
+    }
+
+
+    // The number of arrangements are counted using an NFA.
+    // Solution from https://github.com/clrfl/AdventOfCode2023/blob/master/12/part2.py with some modifications
+    /// Counts the ways the unknown springs can be operational or damaged so that the groups match.
+    pub fn count_number_of_arrangements(&self) -> u64 {
+        let nfa = self.generate_linear_nfa_from_groups();
+
+        let mut state_frequencies: BTreeMap<u64, u64> = BTreeMap::from([
+            (0, 1)
+        ]);
+        let mut next_state_frequencies: BTreeMap<u64, u64> = BTreeMap::new();
+
+        for input in self.condition_symbols.iter() {
+            for kv in &state_frequencies {
+
+                let state_i = *kv.0;
+                let state: &Condition = nfa.get(state_i as usize).unwrap();
+                let state_frequency: u64 = *kv.1;
+
+                let next_state_i: u64 = state_i + 1;
+                let next_state: Option<&Condition> = nfa.get(next_state_i as usize);
+                // let next_state_frequency: u64 = state_frequencies.get(&next_state_i).cloned().unwrap_or(0);
+
+                match input {
+                    Condition::Unknown => {
+                        if next_state.is_some() {
+                            next_state_frequencies.insert(next_state_i, next_state_frequencies.get(&next_state_i).cloned().unwrap_or(0) + state_frequency);
+                        }
+                        if *state == Condition::Functional {
+                            next_state_frequencies.insert(state_i, next_state_frequencies.get(&state_i).cloned().unwrap_or(0) + state_frequency);
+                        }
+                    },
+                    Condition::Functional => {
+                        if next_state.is_some() && *next_state.unwrap() == Condition::Functional {
+                            next_state_frequencies.insert(next_state_i, next_state_frequencies.get(&next_state_i).cloned().unwrap_or(0) + state_frequency);
+                        }
+                        if *state == Condition::Functional {
+                            next_state_frequencies.insert(state_i, next_state_frequencies.get(&state_i).cloned().unwrap_or(0) + state_frequency);
+                        }
+                    },
+                    Condition::Broken => {
+                        if next_state.is_some_and(|next| *next == Condition::Broken) {
+                            next_state_frequencies.insert(next_state_i, next_state_frequencies.get(&next_state_i).cloned().unwrap_or(0) + state_frequency);
+                        }
+                    },
+                }
+            }
+            state_frequencies.clear();
+            next_state_frequencies.clone_into(&mut state_frequencies);
+            next_state_frequencies.clear();
+        }
+        // println!("{:?}", &state_frequencies.values().rev().take(2).cloned().sum::<u64>());
+        
+        // Take the frequencies of the last two states, as they are the only accepting states.
+        return state_frequencies.get(&(nfa.len() as u64 - 1)).cloned().unwrap_or(0) + state_frequencies.get(&(nfa.len() as u64 - 2)).cloned().unwrap_or(0)
+    }
+
+    /// Sums the number of arrangements of every record.
+    pub fn count_all_number_of_arrangements(condition_records: &Vec<ConditionRecord>) -> u64 {
+        // A token that nobody else holds is never cancelled.
+        return Self::count_all_number_of_arrangements_cancellable(condition_records, &Cancellation::new()).unwrap();
+    }
+
+    /// [`ConditionRecord::count_all_number_of_arrangements`], checking `cancellation` before counting each record.
+    /// With the `parallel` feature, the records are shared out between threads.
+    pub fn count_all_number_of_arrangements_cancellable(condition_records: &Vec<ConditionRecord>, cancellation: &Cancellation) -> Result<u64, Cancelled> {
+        #[cfg(feature = "parallel")]
+        let condition_records = condition_records.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let condition_records = condition_records.iter();
+        return condition_records
+                .map(|condition_record| condition_record.count_and_trace_number_of_arrangements(cancellation))
+                .sum::<Result<u64, Cancelled>>();
+    }
+
+    fn count_and_trace_number_of_arrangements(&self, cancellation: &Cancellation) -> Result<u64, Cancelled> {
+        cancellation.check()?;
+        let arrangements = self.count_number_of_arrangements();
+        trace!(
+            springs = self.condition_symbols.iter().map(|&condition| char::from(condition)).collect::<String>(),
+            groups = ?self.group_sizes,
+            arrangements,
+            "counted the arrangements"
+        );
+        return Ok(arrangements);
+    }
+
+    // I initially wanted to use combinations to count, but I eventually realized that there simply were too many cases to solve this with just combinations.
+    /* 
+    pub fn count_number_of_arrangements_combination(&self) -> u64 {
+        let mut number_of_arrangements = 0;
+
+        let condition_symbols_string = self.condition_symbols_to_string();
+        let islands = condition_symbols_string.split(".")
+            .filter(|island| island.len() > 0).collect::<Vec<&str>>();
+        // println!("{:?}", islands.collect::<Vec<&str>>());
+
+        let mut group_sizes: VecDeque<u64> = VecDeque::from_iter(self.group_sizes.iter().cloned());
+        let num_of_separators_needed = group_sizes.len() - islands.len();
+        /*
+        for island in islands {
+            let empty_spaces = island.chars().filter(|e| *e == '?').count() as u64;
+            let broken = island.chars().count() as u64 - empty_spaces;
+
+
+
+            let mut r = 0;
+            let mut spaces_needed = 0;
+
+            loop {
+                let Some(next_group) = group_sizes.pop_front() else {
+                    break;
+                };
+                if r + next_group + spaces_needed + next_group <= empty_spaces {
+                    r += next_group;
+                    spaces_needed = r - 1;
+                } else {
+                    group_sizes.push_front(next_group);
+                    break;
+                }
+            }
+            println!("{} taken {}", empty_spaces - spaces_needed, r);
+            if r > 0 {
+                number_of_arrangements += combination(empty_spaces - spaces_needed, r);
+            }
+        }
+         */
+
+        return number_of_arrangements;
+    }
+    */
+}
+
+// fn combination(n: u64, r: u64) -> u64 {
+//     if r == 0 || n == 1 {
+//         return 1;
+//     }
+//     let numerator: u64 = ((n - r + 1)..=n).reduce(|prev, curr| prev * curr).unwrap();   // Cancels numerator and denominator to mitigate overflows
+//     let r_factorial: u64 = (1..=r).reduce(|prev, next| prev * next).unwrap();
+    
+//     return numerator / r_factorial;
+// }
+
+/// Solver for day 12.
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    type Input = Vec<ConditionRecord>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return ConditionRecord::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return ConditionRecord::count_all_number_of_arrangements(input);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let condition_records_unfolded: Vec<ConditionRecord> = input.iter().map(|condition_record| condition_record.unfold()).collect();
+        return ConditionRecord::count_all_number_of_arrangements(&condition_records_unfolded);
+    }
+
+    fn part_one_cancellable(input: &Self::Input, _params: &Params, cancellation: &Cancellation) -> Result<Self::PartOne, Cancelled> {
+        return ConditionRecord::count_all_number_of_arrangements_cancellable(input, cancellation);
+    }
+
+    fn part_two_cancellable(input: &Self::Input, _params: &Params, cancellation: &Cancellation) -> Result<Self::PartTwo, Cancelled> {
+        let condition_records_unfolded: Vec<ConditionRecord> = input.iter().map(|condition_record| condition_record.unfold()).collect();
+        return ConditionRecord::count_all_number_of_arrangements_cancellable(&condition_records_unfolded, cancellation);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::iter::zip;
+
+    use aoc_common::{Cancellation, Cancelled, Params, Solver};
+    use proptest::prelude::*;
+
+    use crate::{Condition, ConditionRecord, Day12};
+
+    const TEST_INPUT: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    const TEST_INPUT2: &str = "\
+.??..??...?##. 1,1,3
+";
+
+    // #[test]
+    // fn test_combination() {
+    //     let input_output: Vec<((u64, u64), u64)> = Vec::from([
+    //         ((5, 0), 1),
+    //         ((4, 4), 1),
+    //         ((4, 3), 4),
+    //         ((4, 2), 6),
+    //         ((4, 1), 4)
+    //     ]);
+
+    //     for ((n, r), output) in input_output {
+    //         assert_eq!(combination(n, r), output);
+    //     }
+    // }
+
+    #[test]
+    fn test_count_all_number_of_arrangements() {
+        let condition_records = ConditionRecord::parse(TEST_INPUT).unwrap();
+        let correct_answers: Vec<u64> = vec![
+            1, 4, 1, 1, 4, 10
+        ];
+
+        for (output, correct) in zip(condition_records.iter().map(|condition_record| condition_record.count_number_of_arrangements()), correct_answers) {
+            println!("{output} vs {correct}");
+            assert_eq!(output, correct);
+        }
+
+        let condition_records = ConditionRecord::parse(TEST_INPUT2).unwrap();
+        let correct_answers: Vec<u64> = vec![
+            4
+        ];
+
+        for (output, correct) in zip(condition_records.iter().map(|condition_record| condition_record.count_number_of_arrangements()), correct_answers) {
+            println!("{output} vs {correct}");
+            assert_eq!(output, correct);
+        }
+    }
+
+    #[test]
+    fn test_count_all_number_of_arrangements_unfolded() {
+        let condition_records = ConditionRecord::parse(TEST_INPUT).unwrap();
+        let correct_answers: Vec<u64> = vec![
+            1, 16384, 1, 16, 2500, 506250
+        ];
+
+        for (output, correct) in zip(condition_records.iter().map(|condition_record| condition_record.unfold().count_number_of_arrangements()), correct_answers) {
+            println!("{output} vs {correct}");
+            assert_eq!(output, correct);
+        }
+    }
+
+    #[test]
+    fn test_count_all_number_of_arrangements_cancellable() {
+        let condition_records = ConditionRecord::parse(TEST_INPUT).unwrap();
+        assert_eq!(Day12::part_two_cancellable(&condition_records, &Params::new(), &Cancellation::new()), Ok(525152));
+
+        let cancellation = Cancellation::new();
+        cancellation.cancel();
+        assert_eq!(ConditionRecord::count_all_number_of_arrangements_cancellable(&condition_records, &cancellation), Err(Cancelled));
+    }
+
+    // With or without the `parallel` feature, the total is the same as counting the records one at a time.
+    #[test]
+    fn test_count_all_number_of_arrangements_generated() {
+        for seed in 0..20 {
+            let input = aoc_gen::generate(12, seed, &aoc_gen::Size { count: 50, width: 12, height: 5 }).unwrap();
+            let condition_records: Vec<ConditionRecord> = ConditionRecord::parse(&input).unwrap()
+                                                            .iter()
+                                                            .map(|condition_record| condition_record.unfold())
+                                                            .collect();
+            assert_eq!(
+                ConditionRecord::count_all_number_of_arrangements(&condition_records),
+                condition_records.iter().map(|condition_record| condition_record.count_number_of_arrangements()).sum::<u64>()
+            );
+        }
+    }
+
+    /// Tries every way of filling in the unknown springs and keeps those whose groups of damaged springs match.
+    fn brute_force_number_of_arrangements(condition_record: &ConditionRecord) -> u64 {
+        let unknowns: Vec<usize> = (0..condition_record.condition_symbols.len())
+            .filter(|&i| condition_record.condition_symbols[i] == Condition::Unknown)
+            .collect();
+
+        let mut arrangements = 0;
+        for filling in 0..1u64 << unknowns.len() {
+            let mut springs = condition_record.condition_symbols.clone();
+            for (bit, &i) in unknowns.iter().enumerate() {
+                springs[i] = if filling >> bit & 1 == 1 { Condition::Broken } else { Condition::Functional };
+            }
+            let group_sizes: Vec<u64> = springs.split(|condition| *condition != Condition::Broken)
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len() as u64)
+                .collect();
+            if group_sizes == condition_record.group_sizes {
+                arrangements += 1;
+            }
+        }
+        return arrangements;
+    }
+
+    fn condition_record(springs: &str, group_sizes: Vec<u64>) -> ConditionRecord {
+        let condition_symbols = springs.chars().map(|character| Condition::try_from(character).unwrap()).collect();
+        return ConditionRecord { condition_symbols, group_sizes };
+    }
+
+    proptest! {
+        #[test]
+        fn test_number_of_arrangements_matches_brute_force(springs in "[?.#]{1,14}", group_sizes in prop::collection::vec(1..5u64, 1..5)) {
+            let condition_record = condition_record(&springs, group_sizes);
+            prop_assert_eq!(condition_record.count_number_of_arrangements(), brute_force_number_of_arrangements(&condition_record));
+        }
+
+        #[test]
+        fn test_unfolded_number_of_arrangements_matches_brute_force(springs in "[?.#]{1,2}", group_sizes in prop::collection::vec(1..3u64, 1..2)) {
+            let condition_record = condition_record(&springs, group_sizes).unfold();
+            prop_assert_eq!(condition_record.count_number_of_arrangements(), brute_force_number_of_arrangements(&condition_record));
+        }
+    }
+}
\ No newline at end of file
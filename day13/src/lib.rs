@@ -0,0 +1,210 @@
+//--------------------------------------------------------------------------------
+// Day 13: Point of Incidence
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Finds the lines of reflection in each pattern of ash and rocks on Lava Island.
+
+use std::iter::zip;
+use std::io::Read;
+
+use aoc_common::{lines, Line, ParseError, Solver};
+use aoc_grid::Grid;
+
+/// A single pattern of ash and rocks.
+#[derive(Debug)]
+pub struct Terrain {
+    /// The pattern. `true` is a rock (`#`), `false` is ash (`.`).
+    pub grid: Grid<bool>
+}
+
+impl Terrain {
+    /// Reads the patterns from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Vec<Self>, ParseError> {
+        return aoc_common::parse_file(file_name, Self::parse);
+    }
+
+    /// Reads the patterns from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Vec<Self>, ParseError> {
+        return aoc_common::parse_reader(reader, Self::parse);
+    }
+
+    /// Reads the patterns from the puzzle input. Patterns are separated by blank lines.
+    pub fn parse(input: &str) -> Result<Vec<Self>, ParseError> {
+        let mut terrains: Vec<Self> = Vec::new();
+
+        let input_lines: Vec<Line> = lines(input).collect();
+        for pattern_lines in input_lines.split(|line| line.text.is_empty()).filter(|pattern_lines| !pattern_lines.is_empty()) {
+            let grid: Grid<bool> = Grid::from_lines_with(pattern_lines.iter().copied(), |character| {
+                match character {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err("Character is not ash or rock.")
+                }
+            })?;
+            terrains.push(Self { grid });
+        }
+        Ok(terrains)
+    }
+
+    /// Finds the rows above the last horizontal line of reflection of `grid` where exactly `reflection_difference` tiles
+    /// fail to mirror each other.
+    fn detect_horizontal_reflection(grid: &Grid<bool>, reflection_difference: u32) -> Option<usize> {
+        let mut reflection_y: Option<usize> = None;
+
+        for y in 1..grid.height() {
+            let pairs = zip((0..y).rev(), y..grid.height());
+
+            let difference: u32 = pairs.map(|(up_i, down_i)| {
+                return zip(grid.row(up_i), grid.row(down_i))
+                        .filter(|(upper_symbol, lower_symbol)| upper_symbol != lower_symbol)
+                        .count() as u32;
+            })
+            .sum();
+
+            // If all pairs mirror each other
+            if difference == reflection_difference {
+                reflection_y = Some(y);
+            }
+        }
+
+        return reflection_y;
+    }
+        
+    /// Finds the columns left of and the rows above a line of reflection, as `(x, y)`, where exactly
+    /// `reflection_difference` (0 by default) tiles fail to mirror each other.
+    pub fn detect_reflection(&self, reflection_difference: Option<u32>) -> (Option<usize>, Option<usize>) {
+        let reflection_difference = reflection_difference.unwrap_or(0);
+
+        // A vertical line of reflection is a horizontal one once columns become rows.
+        let reflection_y = Self::detect_horizontal_reflection(&self.grid, reflection_difference);
+        let reflection_x = Self::detect_horizontal_reflection(&self.grid.transpose(), reflection_difference);
+
+        return (reflection_x, reflection_y);
+    }
+
+    /// Adds up the columns left of each vertical line of reflection and 100 times the rows above each horizontal one.
+    pub fn summarize_reflections(terrains: &Vec<Terrain>) -> u64 {
+        
+        terrains
+            .iter()
+            .map(|terrain| {
+                let reflections = terrain.detect_reflection(Some(0));
+                let vertical_reflection_value: u64 = match reflections.0 {
+                    Some(x) => x as u64,
+                    None => 0,
+                };
+                let horizontal_reflection_value: u64 = match reflections.1 {
+                    Some(y) => y as u64 * 100,
+                    None => 0,
+                };
+
+                return vertical_reflection_value + horizontal_reflection_value;
+            })
+            .sum()
+
+    }
+
+    /// Summarizes the reflections the way [`Terrain::summarize_reflections`] does, once the single smudge of every pattern is fixed.
+    pub fn summarize_reflections_with_smudge(terrains: &Vec<Terrain>) -> u64 {
+        
+        terrains
+            .iter()
+            .map(|terrain| {
+                let reflections = terrain.detect_reflection(Some(1));
+                let vertical_reflection_value: u64 = match reflections.0 {
+                    Some(x) => x as u64,
+                    None => 0,
+                };
+                let horizontal_reflection_value: u64 = match reflections.1 {
+                    Some(y) => y as u64 * 100,
+                    None => 0,
+                };
+
+                return vertical_reflection_value + horizontal_reflection_value;
+            })
+            .sum()
+
+    }
+
+}
+
+/// Solver for day 13.
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    type Input = Vec<Terrain>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Terrain::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return Terrain::summarize_reflections(input);
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return Terrain::summarize_reflections_with_smudge(input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Terrain;
+    use std::iter::zip;
+
+    const TEST_INPUT: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn test_detect_reflection() {
+        let terrains = Terrain::parse(TEST_INPUT).unwrap();
+        let correct_answers: [(Option<usize>, Option<usize>); 2] = [(Some(5), None), (None, Some(4))];
+        for (terrain, correct_answer) in zip(terrains, correct_answers) {
+            assert_eq!(terrain.detect_reflection(Some(0)), correct_answer);
+        }
+    }
+
+    #[test]
+    fn test_fix_smudge_and_detect_reflection() {
+        let terrains = Terrain::parse(TEST_INPUT).unwrap();
+        let correct_answers: [(Option<usize>, Option<usize>); 2] = [(None, Some(3)), (None, Some(1))];
+        for (terrain, correct_answer) in zip(terrains, correct_answers) {
+            assert_eq!(terrain.detect_reflection(Some(1)), correct_answer);
+        }
+    }
+
+    #[test]
+    fn test_summarize_reflections() {
+        let terrains = Terrain::parse(TEST_INPUT).unwrap();
+        assert_eq!(Terrain::summarize_reflections(&terrains), 405);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Terrain::parse("#.#\n#?#\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = Terrain::parse("#.#\n#.\n\n##\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
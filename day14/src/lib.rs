@@ -0,0 +1,370 @@
+//--------------------------------------------------------------------------------
+// Day 14: Parabolic Reflector Dish
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Tilts the parabolic reflector dish to move the rounded rocks and measures the load on its north support beams.
+
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_common::{Cancellation, Cancelled, Params, ParseError, Solver};
+use aoc_grid::{Direction, Grid, Pos};
+
+/// The platform of the dish.
+#[derive(Clone)]
+pub struct Map {
+    /// The platform: `O` is a rounded rock, `#` a cube-shaped rock and `.` empty space.
+    pub grid: Grid<char>
+}
+
+impl Map {
+    /// Reads the platform from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Self, ParseError> {
+        return aoc_common::parse_file(file_name, Map::parse);
+    }
+
+    /// Reads the platform from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Self, ParseError> {
+        return aoc_common::parse_reader(reader, Map::parse);
+    }
+
+    /// Reads the platform from the puzzle input.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid: Grid<char> = Grid::parse_with(input, |tile| {
+            match tile {
+                'O' | '#' | '.' => Ok(tile),
+                _ => Err("Character is not a rock or empty space.")
+            }
+        })?;
+
+        Ok(Map { grid })
+    }
+
+    /// Tilts the platform so every rounded rock rolls as far north as it can.
+    pub fn slide_north(&mut self) {
+        for j in 0..self.grid.width() {
+            let mut next_empty_space: usize = 0;
+            for i in 0..self.grid.height() {
+                match self.grid[Pos::new(j, i)] {
+                    '#' => {
+                        next_empty_space = i + 1;
+                    },
+                    'O' => {
+                        self.grid[Pos::new(j, i)] = '.';
+                        self.grid[Pos::new(j, next_empty_space)] = 'O';
+                        next_empty_space += 1;
+                    },
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // The other directions turn the platform so that direction faces north, and turn it back after.
+    /// Tilts the platform so every rounded rock rolls as far south as it can.
+    pub fn slide_south(&mut self) {
+        self.grid = self.grid.flip_vertical();
+        self.slide_north();
+        self.grid = self.grid.flip_vertical();
+    }
+    
+    /// Tilts the platform so every rounded rock rolls as far west as it can.
+    pub fn slide_west(&mut self) {
+        self.grid = self.grid.transpose();
+        self.slide_north();
+        self.grid = self.grid.transpose();
+    }
+
+    /// Tilts the platform so every rounded rock rolls as far east as it can.
+    pub fn slide_east(&mut self) {
+        self.grid = self.grid.rotate_counter_clockwise();
+        self.slide_north();
+        self.grid = self.grid.rotate_clockwise();
+    }
+    
+    // Idea to cache the state and check where the loop begins from 
+    // Dr. Neil Smith: https://work.njae.me.uk/
+    // I already encountered this pattern before so it was a bummer that I wasn't able to get it on my own.
+    /// Tilts the platform north, west, south then east, `number_of_cycles` (a billion by default) times.
+    /// The platform soon falls into a loop, so only the spins up to the end of its first loop are actually done.
+    pub fn spin_cycle(&mut self, number_of_cycles: Option<u32>) {
+        // A token that nobody else holds is never cancelled.
+        self.spin_cycle_cancellable(number_of_cycles, &Cancellation::new()).unwrap();
+    }
+
+    /// [`Map::spin_cycle`], checking `cancellation` before each spin. If it is cancelled, the platform is left as it was.
+    pub fn spin_cycle_cancellable(&mut self, number_of_cycles: Option<u32>, cancellation: &Cancellation) -> Result<(), Cancelled> {
+        let number_of_cycles: u32 = number_of_cycles.unwrap_or(1000000000);
+        self.grid = aoc_cycle::try_state_at(self.grid.clone(), |grid| {
+            cancellation.check()?;
+            let mut map = Map { grid: grid.clone() };
+            map.spin();
+            return Ok(map.grid);
+        }, number_of_cycles as u64)?;
+        return Ok(());
+    }
+
+    /// Rolls every rounded rock one tile towards `direction`, if that tile is empty. Returns whether any rock moved.
+    /// Tilting is rolling until no rock moves.
+    pub fn roll(&mut self, direction: Direction) -> bool {
+        // Rocks nearer the edge they roll towards go first, so the ones behind them can follow into their tiles.
+        let mut positions: Vec<Pos> = self.grid.positions().collect();
+        if matches!(direction, Direction::Down | Direction::Right) {
+            positions.reverse();
+        }
+
+        let mut rolled = false;
+        for position in positions {
+            if self.grid[position] != 'O' {
+                continue;
+            }
+            let Some(next_position) = self.grid.step(position, direction) else {
+                continue;
+            };
+            if self.grid[next_position] == '.' {
+                self.grid[position] = '.';
+                self.grid[next_position] = 'O';
+                rolled = true;
+            }
+        }
+        return rolled;
+    }
+
+    /// Tilts the platform towards each of `directions` in turn, a tile at a time. Each item is the platform after
+    /// another roll, starting with the platform as it is, and a tilt is over once no rock can roll any further.
+    pub fn tilt_frames(&self, directions: &[Direction]) -> TiltFrames {
+        return TiltFrames { map: self.clone(), directions: directions.to_vec(), started: false };
+    }
+
+    /// Tilts the platform north, west, south then east once.
+    pub fn spin(&mut self) {
+        self.slide_north();
+        self.slide_west();
+        self.slide_south();
+        self.slide_east();
+    }
+
+    /// Sums the load of every rounded rock: its distance from the south edge, counting its own row.
+    pub fn compute_current_load(&self) -> u32 {
+        let height: usize = self.grid.height();
+        let mut total_load: u32 = 0;
+
+        for (i, row) in self.grid.rows().enumerate() {
+            let distance: u32 = (height - i) as u32;
+            // println!("{distance}");
+            let number_of_rocks = row
+                        .iter()
+                        .filter(|character| **character == 'O')
+                        .count() as u32;
+            total_load += number_of_rocks * distance;
+        }
+
+        total_load
+    }
+
+    
+}
+
+/// The platform being tilted, roll by roll. See [`Map::tilt_frames`].
+pub struct TiltFrames {
+    map: Map,
+    /// The directions left to tilt towards, the current one first.
+    directions: Vec<Direction>,
+    started: bool
+}
+
+impl Iterator for TiltFrames {
+    type Item = Grid<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.map.grid.clone());
+        }
+        while let Some(&direction) = self.directions.first() {
+            if self.map.roll(direction) {
+                return Some(self.map.grid.clone());
+            }
+            self.directions.remove(0);
+        }
+        return None;
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Map::parse(input);
+    }
+}
+
+/// Solver for day 14.
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    type Input = Map;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Map::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let mut map: Map = input.clone();
+        map.slide_north();
+        return map.compute_current_load();
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let mut map: Map = input.clone();
+        map.spin_cycle(None);
+        return map.compute_current_load();
+    }
+
+    fn part_two_cancellable(input: &Self::Input, _params: &Params, cancellation: &Cancellation) -> Result<Self::PartTwo, Cancelled> {
+        let mut map: Map = input.clone();
+        map.spin_cycle_cancellable(None, cancellation)?;
+        return Ok(map.compute_current_load());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Cancellation, Cancelled};
+    use aoc_grid::{Direction, Grid};
+
+    use crate::Map;
+
+    const TEST_INPUT: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn test_slide_north() {
+        let mut map = Map::parse(TEST_INPUT).unwrap();
+        map.slide_north();
+        let correct_answer: Vec<Vec<char>> = vec![
+                vec!['O', 'O', 'O', 'O', '.', '#', '.', 'O', '.', '.'],
+                vec!['O', 'O', '.', '.', '#', '.', '.', '.', '.', '#'],
+                vec!['O', 'O', '.', '.', 'O', '#', '#', '.', '.', 'O'],
+                vec!['O', '.', '.', '#', '.', 'O', 'O', '.', '.', '.'],
+                vec!['.', '.', '.', '.', '.', '.', '.', '.', '#', '.'],
+                vec!['.', '.', '#', '.', '.', '.', '.', '#', '.', '#'],
+                vec!['.', '.', 'O', '.', '.', '#', '.', 'O', '.', 'O'],
+                vec!['.', '.', 'O', '.', '.', '.', '.', '.', '.', '.'],
+                vec!['#', '.', '.', '.', '.', '#', '#', '#', '.', '.'],
+                vec!['#', '.', '.', '.', '.', '#', '.', '.', '.', '.'],
+        ];
+
+        assert_eq!(map.grid, Grid::from_rows(correct_answer).unwrap());
+    }
+
+    #[test]
+    fn test_tilt_frames() {
+        let map = Map::parse(TEST_INPUT).unwrap();
+        let frames: Vec<Grid<char>> = map.tilt_frames(&[Direction::Up]).collect();
+        assert_eq!(frames[0], map.grid);
+        // The rock that rolls furthest is at the bottom of the second column, and it rolls seven tiles.
+        assert_eq!(frames.len(), 1 + 7);
+        let mut tilted = map.clone();
+        tilted.slide_north();
+        assert_eq!(frames.last(), Some(&tilted.grid));
+
+        let spin = [Direction::Up, Direction::Left, Direction::Down, Direction::Right];
+        let mut spun = map.clone();
+        spun.spin();
+        assert_eq!(map.tilt_frames(&spin).last(), Some(spun.grid));
+    }
+
+    #[test]
+    fn test_compute_current_load() {
+        let mut map = Map::parse(TEST_INPUT).unwrap();
+        map.slide_north();
+        let correct_answer = 136;
+
+        assert_eq!(map.compute_current_load(), correct_answer);
+
+        let mut map = Map::parse(TEST_INPUT).unwrap();
+        map.spin_cycle(None);
+        let correct_answer: u32 = 64;
+        assert_eq!(map.compute_current_load(), correct_answer);
+    }
+
+    #[test]
+    fn test_spin_cycle() {
+        let mut map = Map::parse(TEST_INPUT).unwrap();
+        map.spin_cycle(Some(1));
+        let correct_answer: Vec<Vec<char>> = vec![
+            vec!['.', '.', '.', '.', '.', '#', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '#', '.', '.', '.', 'O', '#'],
+            vec!['.', '.', '.', 'O', 'O', '#', '#', '.', '.', '.'],
+            vec!['.', 'O', 'O', '#', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', 'O', 'O', 'O', '#', '.'],
+            vec!['.', 'O', '#', '.', '.', '.', 'O', '#', '.', '#'],
+            vec!['.', '.', '.', '.', 'O', '#', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', 'O', 'O', 'O', 'O'],
+            vec!['#', '.', '.', '.', 'O', '#', '#', '#', '.', '.'],
+            vec!['#', '.', '.', 'O', 'O', '#', '.', '.', '.', '.'],
+        ];
+
+        assert_eq!(map.grid, Grid::from_rows(correct_answer).unwrap());
+        map.spin_cycle(Some(1));
+
+        let correct_answer: Vec<Vec<char>> = vec![
+            vec!['.', '.', '.', '.', '.', '#', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '#', '.', '.', '.', 'O', '#'],
+            vec!['.', '.', '.', '.', '.', '#', '#', '.', '.', '.'],
+            vec!['.', '.', 'O', '#', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', 'O', 'O', 'O', '#', '.'],
+            vec!['.', 'O', '#', '.', '.', '.', 'O', '#', '.', '#'],
+            vec!['.', '.', '.', '.', 'O', '#', '.', '.', '.', 'O'],
+            vec!['.', '.', '.', '.', '.', '.', '.', 'O', 'O', 'O'],
+            vec!['#', '.', '.', 'O', 'O', '#', '#', '#', '.', '.'],
+            vec!['#', '.', 'O', 'O', 'O', '#', '.', '.', '.', 'O'],
+        ];
+
+        assert_eq!(map.grid, Grid::from_rows(correct_answer).unwrap());
+        map.spin_cycle(Some(1));
+
+        let correct_answer: Vec<Vec<char>> = vec![
+            vec!['.', '.', '.', '.', '.', '#', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '#', '.', '.', '.', 'O', '#'],
+            vec!['.', '.', '.', '.', '.', '#', '#', '.', '.', '.'],
+            vec!['.', '.', 'O', '#', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', 'O', 'O', 'O', '#', '.'],
+            vec!['.', 'O', '#', '.', '.', '.', 'O', '#', '.', '#'],
+            vec!['.', '.', '.', '.', 'O', '#', '.', '.', '.', 'O'],
+            vec!['.', '.', '.', '.', '.', '.', '.', 'O', 'O', 'O'],
+            vec!['#', '.', '.', '.', 'O', '#', '#', '#', '.', 'O'],
+            vec!['#', '.', 'O', 'O', 'O', '#', '.', '.', '.', 'O'],
+        ];
+        assert_eq!(map.grid, Grid::from_rows(correct_answer).unwrap());
+    }
+
+    #[test]
+    fn test_spin_cycle_cancelled() {
+        let mut map = Map::parse(TEST_INPUT).unwrap();
+        let cancellation = Cancellation::new();
+        cancellation.cancel();
+        assert_eq!(map.spin_cycle_cancellable(None, &cancellation), Err(Cancelled));
+        assert_eq!(map.grid, Map::parse(TEST_INPUT).unwrap().grid);
+
+        assert_eq!(map.spin_cycle_cancellable(None, &Cancellation::new()), Ok(()));
+        assert_eq!(map.compute_current_load(), 64);
+    }
+}
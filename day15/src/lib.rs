@@ -0,0 +1,258 @@
+//--------------------------------------------------------------------------------
+// Day 15: Lens Library
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Runs the initialization sequence of the lava production facility's boxes of lenses.
+
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_common::{lines, Line, ParseError, Solver};
+use tracing::{debug, trace};
+
+/// A labelled lens in one of the boxes.
+#[derive(Debug, Clone)]
+pub struct Lens {
+    /// The label of the step that put the lens in its box.
+    pub label: String,
+    /// The focal length of the lens, from 1 to 9.
+    pub focal_length: u16, 
+}
+
+/// The initialization sequence and the 256 boxes it arranges lenses in.
+#[derive(Debug, Clone)]
+pub struct Facility {
+    /// The comma-separated steps of the sequence.
+    pub sequence: Vec<String>,
+    /// The boxes, indexed by the HASH of a lens's label.
+    pub hash_map: Vec<Vec<Lens>>
+}
+
+impl Facility {
+    /// Reads the sequence from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Self, ParseError> {
+        return aoc_common::parse_file(file_name, Facility::parse);
+    }
+
+    /// Reads the sequence from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Self, ParseError> {
+        return aoc_common::parse_reader(reader, Facility::parse);
+    }
+
+    /// Reads the sequence from the puzzle input. The boxes start empty.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut sequence: Vec<String> = vec![];
+        let mut hash_map: Vec<Vec<Lens>> = Vec::new();
+
+        for _ in 0..256 {
+            hash_map.push(Vec::new());
+        }
+
+        for line in lines(input).filter(|line| !line.text.trim().is_empty()) {
+            for step in line.text.trim().split(",") {
+                Self::check_step(&line, step)?;
+                sequence.push(step.to_string());
+            }
+        }
+        if sequence.is_empty() {
+            return Err(ParseError::end_of_input(input, "expected an initialization sequence"));
+        }
+
+        Ok(Facility { sequence, hash_map })
+    }
+
+    /// Makes sure `step` is either `label=focal_length` or `label-`.
+    fn check_step(line: &Line, step: &str) -> Result<(), ParseError> {
+        let label = match step.strip_suffix("-") {
+            Some(label) => label,
+            None => {
+                let (label, focal_length) = line.split_once(step, "=")?;
+                if !matches!(line.parse::<u16>(focal_length)?, 1..=9) {
+                    return Err(line.error(focal_length, format!("expected a focal length from 1 to 9, found {focal_length}")));
+                }
+                label
+            }
+        };
+        if label.is_empty() || !label.chars().all(|character| character.is_ascii_lowercase()) {
+            return Err(line.error(step, format!("expected a label of lowercase letters in `{step}`")));
+        }
+        Ok(())
+    }
+
+    /// The Holiday ASCII String Helper algorithm (HASH), which turns `step` into a number from 0 to 255.
+    pub fn hash(step: &String) -> i32 {
+        let mut reduced: i32 = 0;
+
+        for byte in step.as_bytes() {
+            reduced += *byte as i32;
+            reduced *= 17;
+            reduced %= 256;
+        }
+
+        reduced
+    }
+
+    /// Puts lenses in (`=`) and takes them out of (`-`) the boxes as each step says.
+    pub fn process_sequence(&mut self) {
+        for step in &self.sequence {
+            match step.split_once("=") {
+                Some((label, focal_length)) => {
+                    let hash_value = Self::hash(&label.to_string());
+                    if let (Some(lens_box), Ok(focal_length)) = (self.hash_map.get_mut(hash_value as usize), focal_length.parse()) {
+                        if let Some(to_remove) = lens_box.iter().position(|lens| lens.label == label) {
+                            lens_box[to_remove].focal_length = focal_length;
+                        } else {
+                            lens_box.push(Lens { label: label.to_string(), focal_length });
+                        }
+                    }
+                },
+                None => {
+                    let label = step.trim_end_matches("-");
+                    let hash_value = Self::hash(&label.to_string());
+                    if let Some(lens_box) = self.hash_map.get_mut(hash_value as usize) {
+                        if let Some(to_remove) = lens_box.iter().position(|lens| lens.label == label) {
+                            lens_box.remove(to_remove);
+                        }
+                    }
+                }
+            }
+            trace!(step, "followed a step");
+        }
+        debug!(
+            boxes = ?self.hash_map.iter().enumerate().filter(|(_, lens_box)| !lens_box.is_empty()).collect::<Vec<_>>(),
+            "arranged the lenses"
+        );
+    }
+
+    /// Sums the focusing power of every lens: its box number times its slot number times its focal length, all counting from 1.
+    pub fn get_focusing_power(&self) -> u32 {
+        let non_empty_boxes = self.hash_map
+                                .iter();
+
+        non_empty_boxes.enumerate()
+            .map(|(box_number, lens_box)| {
+                lens_box
+                    .iter()
+                    .enumerate()
+                    .map(|(lens_number, lens)| {
+                        let sum: u32 = (box_number as u32 + 1) * (lens_number as u32 + 1) * lens.focal_length as u32;
+                        sum
+                    })
+                    .sum::<u32>()
+            })
+            .sum::<u32>()
+    }
+
+    /// Sums the HASH of every step of the sequence.
+    pub fn accumulate_hash(&self) -> i32 {
+        return self.sequence
+                .iter()
+                .map(Facility::hash)
+                .sum()
+    }
+}
+
+impl FromStr for Facility {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Facility::parse(input);
+    }
+}
+
+/// Solver for day 15.
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    type Input = Facility;
+    type PartOne = i32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Facility::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return input.accumulate_hash();
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let mut facility: Facility = input.clone();
+        facility.process_sequence();
+        return facility.get_focusing_power();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Facility;
+
+    const TEST_INPUT: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+    #[test]
+    pub fn test_parse() {
+        let initialization_sequence = Facility::parse(TEST_INPUT).unwrap();
+        assert_eq!(initialization_sequence.sequence, vec![
+            "rn=1",
+            "cm-",
+            "qp=3",
+            "cm=2",
+            "qp-",
+            "pc=4",
+            "ot=9",
+            "ab=5",
+            "pc-",
+            "pc=6",
+            "ot=7"
+        ])
+    }
+
+    #[test]
+    pub fn test_hash() {
+        let input_output: Vec<(String, i32)> = vec![      
+            ("rn=1".to_string(), 30),
+            ("cm-".to_string(), 253),
+            ("qp=3".to_string(), 97),
+            ("cm=2".to_string(), 47),
+            ("qp-".to_string(), 14),
+            ("pc=4".to_string(), 180),
+            ("ot=9".to_string(), 9),
+            ("ab=5".to_string(), 197),
+            ("pc-".to_string(), 48),
+            ("pc=6".to_string(), 214),
+            ("ot=7".to_string(), 231),
+        ];
+
+        for (input, output) in input_output {
+            assert_eq!(Facility::hash(&input), output);
+        }
+    }
+
+    #[test]
+    pub fn test_accumulate_hash() {
+        let facility = Facility::parse(TEST_INPUT).unwrap();
+        assert_eq!(facility.accumulate_hash(), 1320);
+    }
+
+    #[test]
+    pub fn test_focusing_power() {
+        let mut facility = Facility::parse(TEST_INPUT).unwrap();
+        facility.process_sequence();
+        assert_eq!(facility.get_focusing_power(), 145);
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let error = Facility::parse("rn=1,cm-,qp=0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 13));
+        let error = Facility::parse("rn=1,cm?,qp=3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert!(Facility::parse("\n").is_err());
+    }
+}
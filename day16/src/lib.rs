@@ -0,0 +1,332 @@
+//--------------------------------------------------------------------------------
+// Day 16: The Floor Will Be Lava
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Traces the beam of light bouncing around the contraption of mirrors and splitters to see which tiles it energizes.
+
+use std::collections::HashSet;
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_common::{Cancellation, Cancelled, Params, ParseError, Solver};
+use aoc_grid::{Direction, Grid, Pos};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::{debug, trace};
+
+/// A beam of light entering a tile.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Beam {
+    /// The tile the beam is on.
+    pub position: Pos,
+    /// Where the beam is heading.
+    pub direction: Direction
+}
+
+impl Beam {
+    /// Moves the beam one tile in its direction, unless that takes it off `grid`.
+    pub fn traverse<T>(&self, grid: &Grid<T>) -> Option<Beam> {
+        let position = grid.step(self.position, self.direction)?;
+        return Some(Beam { position, direction: self.direction });
+    }
+}
+
+/// A tile of the contraption.
+#[derive(Debug, Clone)]
+pub enum MapElement {
+    Passthrough,
+    Mirror {
+        symbol: char
+    }
+}
+
+impl TryFrom<char> for MapElement {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(MapElement::Passthrough),
+            '/' | '|' | '-' | '\\' => Ok(MapElement::Mirror { symbol: value }),
+            _ => Err("Character is not a MapElement.")
+        }
+    }
+}
+
+/// The contraption of mirrors and splitters.
+pub struct Cave {
+    /// The tiles.
+    pub grid: Grid<MapElement>,
+}
+
+impl Cave {
+    /// Reads the contraption from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Self, ParseError> {
+        return aoc_common::parse_file(file_name, Cave::parse);
+    }
+
+    /// Reads the contraption from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Self, ParseError> {
+        return aoc_common::parse_reader(reader, Cave::parse);
+    }
+
+    /// Reads the contraption from the puzzle input.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid: Grid<MapElement> = Grid::parse(input)?;
+
+        Ok(Cave { grid })
+    }
+
+    fn beam_incident(beam: Beam, tile: &MapElement, directions_reflected: &mut HashSet<Direction>) -> Vec<Beam> {
+        let mut resulting_beams: Vec<Beam> = vec![];
+        match tile {
+            MapElement::Passthrough => {
+                resulting_beams.push(beam);
+            }
+            MapElement::Mirror { symbol } => {
+                let beam_direction = beam.direction;
+                if directions_reflected.contains(&beam_direction) {
+                    match (beam_direction, symbol) {
+                        (Direction::Right, '/') | (Direction::Left, '\\') => {
+                            resulting_beams.push(Beam { direction: Direction::Up, position: beam.position });
+                        },
+                        (Direction::Right, '\\') | (Direction::Left, '/') => {
+                            resulting_beams.push(Beam { direction: Direction::Down, position: beam.position });
+                        },
+                        (Direction::Down, '/') | (Direction::Up, '\\') => {
+                            resulting_beams.push(Beam { direction: Direction::Left, position: beam.position });
+                        },
+                        (Direction::Up, '/') | (Direction::Down, '\\') => {
+                            resulting_beams.push(Beam { direction: Direction::Right, position: beam.position });
+                        },
+                        (Direction::Right, '|') | (Direction::Left, '|') => {
+                            Vec::extend_from_slice(&mut resulting_beams, &[Beam { direction: Direction::Up, position: beam.position }, Beam { direction: Direction::Down, position: beam.position }]);
+                        },
+                        (Direction::Up, '-') | (Direction::Down, '-') => {
+                            Vec::extend_from_slice(&mut resulting_beams, &[Beam { direction: Direction::Left, position: beam.position }, Beam { direction: Direction::Right, position: beam.position }]);
+                        },
+                        (Direction::Up, '|') | (Direction::Down, '|') | (Direction::Left, '-') | (Direction::Right, '-') => {
+                            resulting_beams.push(beam);
+                        },
+                        _ => unreachable!(),
+                    }
+                    directions_reflected.remove(&beam_direction);
+                }
+            }
+        }
+
+        return resulting_beams;
+    }
+
+    /// Follows `starting_beam`, which by default enters the top-left tile heading right, one tile at a time. Each item
+    /// is every beam entering a tile at the same step, so the first is just the starting beam.
+    pub fn beam_fronts(&self, starting_beam: Option<Beam>) -> BeamFronts<'_> {
+        let starting_beam: Beam = starting_beam.unwrap_or(Beam { position: Pos::new(0, 0), direction: Direction::Right });
+        let reflection_status: Grid<HashSet<Direction>> = Grid::filled(self.grid.width(), self.grid.height(), HashSet::from(Direction::ALL));
+
+        return BeamFronts { cave: self, beams: Vec::from( [ starting_beam ] ), reflection_status };
+    }
+
+    /// Finds every tile energized by `starting_beam`, which by default enters the top-left tile heading right.
+    pub fn trace_beams(&self, starting_beam: Option<Beam>) -> HashSet<Pos> {
+        let mut energized: HashSet<Pos> = HashSet::new();
+        for beams in self.beam_fronts(starting_beam) {
+            energized.extend(beams.iter().map(|beam| beam.position));
+            trace!(beams = beams.len(), "energized:\n{}", self.energized_grid(&energized));
+        }
+        energized
+    }
+
+    /// Every beam that can enter the contraption: one from each edge tile, heading away from that edge.
+    pub fn edge_beams(&self) -> Vec<Beam> {
+        let width = self.grid.width();
+        let height = self.grid.height();
+
+        let mut beams: Vec<Beam> = Vec::new();
+        for x in 0..width {
+            beams.push(Beam { position: Pos::new(x, 0), direction: Direction::Down });
+            beams.push(Beam { position: Pos::new(x, height - 1), direction: Direction::Up });
+        }
+        for y in 0..height {
+            beams.push(Beam { position: Pos::new(0, y), direction: Direction::Right });
+            beams.push(Beam { position: Pos::new(width - 1, y), direction: Direction::Left });
+        }
+        return beams;
+    }
+
+    /// The most tiles that can be energized by a beam entering from any edge tile.
+    pub fn get_max_energized(&self) -> u32 {
+        // A token that nobody else holds is never cancelled.
+        return self.get_max_energized_cancellable(&Cancellation::new()).unwrap();
+    }
+
+    /// [`Cave::get_max_energized`], checking `cancellation` before tracing each beam.
+    /// With the `parallel` feature, the beams are shared out between threads.
+    pub fn get_max_energized_cancellable(&self, cancellation: &Cancellation) -> Result<u32, Cancelled> {
+        let beams = self.edge_beams();
+        #[cfg(feature = "parallel")]
+        let beams = beams.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let beams = beams.into_iter();
+        let energized: Vec<usize> = beams.map(|beam| self.count_energized_from_edge(beam, cancellation)).collect::<Result<_, Cancelled>>()?;
+        return Ok(energized.into_iter().max().unwrap_or(0) as u32);
+    }
+
+    fn count_energized_from_edge(&self, beam: Beam, cancellation: &Cancellation) -> Result<usize, Cancelled> {
+        cancellation.check()?;
+        let energized = self.trace_beams(Some(beam.clone())).len();
+        debug!(?beam, energized, "traced a beam from an edge tile");
+        return Ok(energized);
+    }
+
+    /// The contraption with the `energized` tiles marked `#`.
+    pub fn energized_grid(&self, energized: &HashSet<Pos>) -> Grid<char> {
+        return Grid::from_fn(self.grid.width(), self.grid.height(), |position| {
+            if energized.contains(&position) {
+                return '#';
+            }
+            return '.';
+        });
+    }
+
+    /// Prints the contraption with the `energized` tiles marked `#`.
+    pub fn print_energized(&self, energized: &HashSet<Pos>) {
+        print!("{}", self.energized_grid(energized));
+    }
+
+}
+
+/// The beams of light moving through the contraption, step by step. See [`Cave::beam_fronts`].
+pub struct BeamFronts<'a> {
+    cave: &'a Cave,
+    beams: Vec<Beam>,
+    /// The directions each mirror has not reflected yet. A mirror only reflects a direction once, since a second beam
+    /// reflected the same way would retrace the first.
+    reflection_status: Grid<HashSet<Direction>>
+}
+
+impl Iterator for BeamFronts<'_> {
+    type Item = Vec<Beam>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.beams.is_empty() {
+            return None;
+        }
+
+        let mut next_beams: Vec<Beam> = vec![];
+        for beam in self.beams.iter().rev() {
+            let position = beam.position;
+            let resulting_beams = Cave::beam_incident(beam.clone(), &self.cave.grid[position], &mut self.reflection_status[position]);
+
+            next_beams.extend(resulting_beams
+                .iter()
+                .filter_map(|beam| beam.traverse(&self.cave.grid))
+            );
+        }
+        return Some(std::mem::replace(&mut self.beams, next_beams));
+    }
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Cave::parse(input);
+    }
+}
+
+/// Solver for day 16.
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+
+    type Input = Cave;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Cave::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return input.trace_beams(None).len();
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return input.get_max_energized();
+    }
+
+    fn part_two_cancellable(input: &Self::Input, _params: &Params, cancellation: &Cancellation) -> Result<Self::PartTwo, Cancelled> {
+        return input.get_max_energized_cancellable(cancellation);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_grid::{Direction, Pos};
+
+    use crate::{Beam, Cave};
+
+    const TEST_INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn test_trace_beams() {
+        let cave: Cave = Cave::parse(TEST_INPUT).unwrap();
+        let energized = cave.trace_beams(None);
+
+        assert_eq!(energized.len(), 46);
+    }
+
+    #[test]
+    fn test_beam_fronts() {
+        let cave: Cave = Cave::parse(TEST_INPUT).unwrap();
+        let fronts: Vec<Vec<Beam>> = cave.beam_fronts(None).collect();
+        assert_eq!(fronts[0], vec![Beam { position: Pos::new(0, 0), direction: Direction::Right }]);
+        assert_eq!(fronts[1], vec![Beam { position: Pos::new(1, 0), direction: Direction::Right }]);
+        // The splitter at (1, 0) sends the beam down only, since it cannot go up from the top row.
+        assert_eq!(fronts[2], vec![Beam { position: Pos::new(1, 1), direction: Direction::Down }]);
+        assert!(fronts.iter().all(|beams| !beams.is_empty()));
+    }
+
+    #[test]
+    fn test_max_energized() {
+        let cave: Cave = Cave::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(cave.get_max_energized(), 51);
+    }
+
+    // With or without the `parallel` feature, the most is the same as tracing the beams one at a time.
+    #[test]
+    fn test_max_energized_cancellable() {
+        use aoc_common::Cancellation;
+
+        for seed in 0..20 {
+            let input = aoc_gen::generate(16, seed, &aoc_gen::Size { count: 1, width: 20, height: 15 }).unwrap();
+            let cave: Cave = Cave::parse(&input).unwrap();
+            let one_at_a_time = cave.edge_beams().into_iter().map(|beam| cave.trace_beams(Some(beam)).len()).max().unwrap() as u32;
+            assert_eq!(cave.get_max_energized_cancellable(&Cancellation::new()), Ok(one_at_a_time));
+        }
+
+        let cancellation = Cancellation::new();
+        cancellation.cancel();
+        assert!(Cave::parse(TEST_INPUT).unwrap().get_max_energized_cancellable(&cancellation).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Cave::parse(".|.\n.x.\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,280 @@
+//--------------------------------------------------------------------------------
+// Day 17: Clumsy Crucible
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Finds the path through the city that loses the least heat for a crucible that cannot turn on a dime.
+
+use std::{io::Read, str::FromStr};
+
+use aoc_common::{Cancellation, Cancelled, MaybeAnswer, Params, ParseError, Solver};
+use aoc_grid::{Direction, Grid, Pos};
+
+/// The map of the city blocks and their heat loss.
+#[derive(Debug, PartialEq)]
+pub struct City {
+    /// The heat lost entering each block.
+    pub map: Grid<u16>
+}
+
+// fn manhattan_distance(a: (isize, isize), b: (isize, isize)) -> usize {
+//     return a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+// }
+
+impl City {
+    /// Reads the map from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<City, ParseError> {
+        return aoc_common::parse_file(file_name, City::parse);
+    }
+
+    /// Reads the map from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<City, ParseError> {
+        return aoc_common::parse_reader(reader, City::parse);
+    }
+
+    /// Reads the map from the puzzle input.
+    pub fn parse(input: &str) -> Result<City, ParseError> {
+        let map: Grid<u16> = Grid::parse_with(input, |character| {
+            match character.to_digit(10) {
+                Some(heat_loss) => Ok(heat_loss as u16),
+                None => Err("Character is not a digit.")
+            }
+        })?;
+
+        Ok(City { map })
+    }
+
+
+    // Used Axel Lindeberg's solution because my Dijkstra implementation wouldn't work
+    // https://github.com/AxlLind/AdventOfCode2023/blob/main/src/bin/17.rs
+
+    /// The least heat lost going from the top-left block to the bottom-right one, when the crucible must move at least
+    /// `minimum_movement` (1 by default) and at most `maximum_movement` (3 by default) blocks before turning, or `None`
+    /// if it cannot get there, like when the city is narrower than the crucible's minimum run.
+    pub fn dijkstra(&self, minimum_movement: Option<isize>, maximum_movement: Option<isize>) -> Option<u16> {
+        return Some(self.least_heat_loss_path(minimum_movement, maximum_movement)?.1);
+    }
+
+    /// Every block the crucible passes through on the way that loses the least heat, starting from the top-left one,
+    /// along with the heat lost, or `None` if there is no such way. See [`City::dijkstra`].
+    pub fn least_heat_loss_path(&self, minimum_movement: Option<isize>, maximum_movement: Option<isize>) -> Option<(Vec<Pos>, u16)> {
+        // A token that nobody else holds is never cancelled.
+        return self.least_heat_loss_path_cancellable(minimum_movement, maximum_movement, &Cancellation::new()).unwrap();
+    }
+
+    /// [`City::least_heat_loss_path`], checking `cancellation` before looking at where the crucible can go next from
+    /// each block.
+    pub fn least_heat_loss_path_cancellable(&self, minimum_movement: Option<isize>, maximum_movement: Option<isize>, cancellation: &Cancellation) -> Result<Option<(Vec<Pos>, u16)>, Cancelled> {
+        let goal = Pos::new(self.map.width() - 1, self.map.height() - 1);
+        let successors = |&(current, current_direction): &(Pos, Option<Direction>)| {
+            cancellation.check()?;
+            let mut next_states: Vec<((Pos, Option<Direction>), u16)> = Vec::new();
+            for direction in [Direction::Down, Direction::Up, Direction::Right, Direction::Left] {
+                // The crucible has already gone as far as it wants in this direction, and it cannot turn back.
+                if current_direction.is_some_and(|current_direction| direction == current_direction || direction == current_direction.reverse()) {
+                    continue;
+                }
+
+                let mut next_cost = 0;
+
+                for skip in 1..=maximum_movement.unwrap_or(3) {
+                    let Some(next_position) = self.map.steps(current, direction, skip as usize) else {
+                        continue;
+                    };
+
+                    next_cost += self.map[next_position];
+
+                    if skip < minimum_movement.unwrap_or(1) {
+                        continue;
+                    }
+
+                    next_states.push(((next_position, Some(direction)), next_cost));
+                }
+            }
+            return Ok(next_states);
+        };
+
+        let Some((states, heat_loss)) = aoc_search::try_dijkstra((Pos::new(0, 0), None), successors, |&(current, _)| current == goal)? else {
+            return Ok(None);
+        };
+
+        // The crucible moves several blocks between states, so fill in the blocks it went through.
+        let mut path: Vec<Pos> = vec![Pos::new(0, 0)];
+        for &(next_position, direction) in &states[1..] {
+            let direction = direction.unwrap();
+            while *path.last().unwrap() != next_position {
+                path.push(self.map.step(*path.last().unwrap(), direction).unwrap());
+            }
+        }
+        return Ok(Some((path, heat_loss)));
+    }
+}
+
+
+impl FromStr for City {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return City::parse(input);
+    }
+}
+
+/// Solver for day 17. A part has no answer if the crucible cannot reach the far corner of the city.
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+
+    type Input = City;
+    type PartOne = MaybeAnswer<u16>;
+    type PartTwo = MaybeAnswer<u16>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return City::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return MaybeAnswer(input.dijkstra(None, None));
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return MaybeAnswer(input.dijkstra(Some(4), Some(10)));
+    }
+
+    fn part_one_cancellable(input: &Self::Input, _params: &Params, cancellation: &Cancellation) -> Result<Self::PartOne, Cancelled> {
+        let path = input.least_heat_loss_path_cancellable(None, None, cancellation)?;
+        return Ok(MaybeAnswer(path.map(|(_, heat_loss)| heat_loss)));
+    }
+
+    fn part_two_cancellable(input: &Self::Input, _params: &Params, cancellation: &Cancellation) -> Result<Self::PartTwo, Cancelled> {
+        let path = input.least_heat_loss_path_cancellable(Some(4), Some(10), cancellation)?;
+        return Ok(MaybeAnswer(path.map(|(_, heat_loss)| heat_loss)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::Duration;
+
+    use aoc_common::{Cancellation, Cancelled, Params, Solver};
+    use aoc_grid::{Grid, Pos};
+
+    use crate::{City, Day17};
+
+    const TEST_INPUT: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn test_parse() {
+        let city: City = City::parse(TEST_INPUT).unwrap();
+        assert_eq!(city, City { map: Grid::from_rows(vec![
+            vec![2, 4, 1, 3, 4, 3, 2, 3, 1, 1, 3, 2, 3],
+            vec![3, 2, 1, 5, 4, 5, 3, 5, 3, 5, 6, 2, 3],
+            vec![3, 2, 5, 5, 2, 4, 5, 6, 5, 4, 2, 5, 4],
+            vec![3, 4, 4, 6, 5, 8, 5, 8, 4, 5, 4, 5, 2],
+            vec![4, 5, 4, 6, 6, 5, 7, 8, 6, 7, 5, 3, 6],
+            vec![1, 4, 3, 8, 5, 9, 8, 7, 9, 8, 4, 5, 4],
+            vec![4, 4, 5, 7, 8, 7, 6, 9, 8, 7, 7, 6, 6],
+            vec![3, 6, 3, 7, 8, 7, 7, 9, 7, 9, 6, 5, 3],
+            vec![4, 6, 5, 4, 9, 6, 7, 9, 8, 6, 8, 8, 7],
+            vec![4, 5, 6, 4, 6, 7, 9, 9, 8, 6, 4, 5, 3],
+            vec![1, 2, 2, 4, 6, 8, 6, 8, 6, 5, 5, 6, 3],
+            vec![2, 5, 4, 6, 5, 4, 8, 8, 8, 7, 7, 3, 5],
+            vec![4, 3, 2, 2, 6, 7, 4, 6, 5, 5, 5, 3, 3],
+        ]).unwrap() })
+    }
+
+    #[test]
+    fn test_transport_cauldron() {
+        let city: City = City::parse(TEST_INPUT).unwrap();
+        let min_heat = city.dijkstra(None, None);
+        // let mut valid_path: HashSet<(isize, isize)> = HashSet::new();
+        // assert!(path.contains_key(&(city.width - 1, city.height - 1)));
+
+        // let mut heat_loss = 0;
+        // let mut current_position: Option<(isize, isize)> = path.get(&(city.width - 1, city.height - 1)).cloned();
+
+        // while current_position.is_some_and(|pos| pos != (0, 0)) {
+        //     valid_path.insert(current_position.unwrap());
+        //     let current_position_unwrapped = current_position.clone().unwrap();
+        //     let current_coordinate = current_position_unwrapped;
+        //     heat_loss += city.map[current_coordinate.1 as usize][current_coordinate.0 as usize];
+        //     current_position = path.get(&current_position_unwrapped).cloned();
+        // }
+
+        // for i in 0..city.height {
+        //     for j in 0..city.width {
+        //         match valid_path.get(&(j, i)) {
+        //             None => {
+        //                 print!("{}", city.map[i as usize][j as usize])
+        //             },
+        //             Some(_) => {
+        //                 print!("-");
+        //             }
+        //         }
+        //     }
+        //     println!();
+        // }
+
+        assert_eq!(min_heat, Some(102))
+    }
+
+    #[test]
+    fn test_least_heat_loss_path() {
+        let city: City = City::parse(TEST_INPUT).unwrap();
+        for (minimum_movement, maximum_movement) in [(None, None), (Some(4), Some(10))] {
+            let (path, heat_loss) = city.least_heat_loss_path(minimum_movement, maximum_movement).unwrap();
+            assert_eq!(path[0], Pos::new(0, 0));
+            assert_eq!(*path.last().unwrap(), Pos::new(12, 12));
+            assert!(path.windows(2).all(|pair| pair[0].manhattan_distance(pair[1]) == 1));
+            assert_eq!(path[1..].iter().map(|&position| city.map[position]).sum::<u16>(), heat_loss);
+        }
+        assert_eq!(city.least_heat_loss_path(Some(4), Some(10)).unwrap().1, 94);
+    }
+
+    // The ultra crucible moves at least four blocks before it can stop, so a city less than five blocks across in
+    // either direction has no way to the far corner for it.
+    #[test]
+    fn test_small_city() {
+        let city: City = City::parse("123\n456\n789\n").unwrap();
+        assert_eq!(city.dijkstra(None, None), Some(20));
+        assert_eq!(city.least_heat_loss_path(Some(4), Some(10)), None);
+        assert_eq!(Day17::part_two(&city).to_string(), "none");
+
+        let city: City = City::parse("11111\n11111\n").unwrap();
+        assert_eq!(city.dijkstra(Some(4), Some(10)), None);
+        assert_eq!(City::parse("5\n").unwrap().dijkstra(Some(4), Some(10)), Some(0));
+    }
+
+    #[test]
+    fn test_cancellable() {
+        let city: City = City::parse(TEST_INPUT).unwrap();
+        assert_eq!(Day17::part_two_cancellable(&city, &Params::new(), &Cancellation::new()).unwrap().to_string(), "94");
+
+        let cancellation = Cancellation::new();
+        cancellation.cancel();
+        assert_eq!(city.least_heat_loss_path_cancellable(None, None, &cancellation), Err(Cancelled));
+        assert_eq!(Day17::part_one_cancellable(&city, &Params::new(), &cancellation), Err(Cancelled));
+
+        // A big city takes far longer than a millisecond to search, so the budget runs out part of the way through.
+        let input: String = (0..150).map(|y| (0..150).map(|x| char::from(b'1' + ((x * 7 + y * 13) % 9) as u8)).chain(['\n']).collect::<String>()).collect();
+        let city: City = City::parse(&input).unwrap();
+        let cancellation = Cancellation::with_budget(Duration::from_millis(1));
+        assert_eq!(Day17::part_two_cancellable(&city, &Params::new(), &cancellation), Err(Cancelled));
+    }
+}
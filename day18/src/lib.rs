@@ -0,0 +1,271 @@
+//--------------------------------------------------------------------------------
+// Day 18: Lavaduct Lagoon
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Measures how much lava the lagoon dug out from the dig plan can hold.
+
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_common::{lines, Line, ParseError, Solver};
+use aoc_grid::Direction;
+use aoc_math::lattice_points;
+
+/// One instruction of the dig plan.
+pub struct DigStep {
+    /// Which way to dig.
+    pub direction: Direction,
+    /// How many metres to dig.
+    pub steps: u64,
+    /// The colour to paint the trench, as `0xrrggbb`.
+    pub colour: u32,
+}
+
+/// The instructions for digging the edge of the lagoon.
+pub struct DigPlan {
+    /// The instructions, in order.
+    pub plan: Vec<DigStep>
+}
+
+impl DigPlan {
+    /// Reads the plan from `file_name`, as [`DigPlan::parse`] does.
+    pub fn load_from_file(file_name: &str) -> Result<Self, ParseError> {
+        return aoc_common::parse_file(file_name, DigPlan::parse);
+    }
+
+    /// Reads the plan from `reader`, as [`DigPlan::parse`] does.
+    pub fn from_reader(reader: impl Read) -> Result<Self, ParseError> {
+        return aoc_common::parse_reader(reader, DigPlan::parse);
+    }
+
+    /// Reads the plan from the direction and distance of each line of the puzzle input.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut plan: Vec<DigStep> = vec![];
+
+        for line in lines(input) {
+            let (direction, steps, color_str) = Self::read_line(&line)?;
+            plan.push(DigStep { direction, steps, colour: Self::read_colour(color_str) });
+        }
+        
+        Ok(DigPlan { plan })
+    }
+
+    /// Reads the plan from `file_name`, as [`DigPlan::parse_corrected`] does.
+    pub fn load_from_file_corrected(file_name: &str) -> Result<Self, ParseError> {
+        return aoc_common::parse_file(file_name, DigPlan::parse_corrected);
+    }
+
+    /// Reads the plan from `reader`, as [`DigPlan::parse_corrected`] does.
+    pub fn from_reader_corrected(reader: impl Read) -> Result<Self, ParseError> {
+        return aoc_common::parse_reader(reader, DigPlan::parse_corrected);
+    }
+
+    /// Reads the plan from the hexadecimal code of each line of the puzzle input, where the first five digits are
+    /// the distance and the last one is the direction.
+    pub fn parse_corrected(input: &str) -> Result<Self, ParseError> {
+        let mut plan: Vec<DigStep> = vec![];
+
+        for line in lines(input) {
+            let (_, _, color_str) = Self::read_line(&line)?;
+            let steps = u64::from_str_radix(&color_str[..5], 16)
+                            .map_err(|error| line.error(color_str, format!("could not read `{}`: {error}", &color_str[..5])))?;
+            let direction: Direction = match &color_str[5..] {
+                "0" => Direction::Right,
+                "1" => Direction::Down,
+                "2" => Direction::Left,
+                "3" => Direction::Up,
+                direction_id => return Err(line.error(direction_id, format!("expected a direction from 0 to 3, found `{direction_id}`")))
+            };
+
+            plan.push(DigStep { direction, steps, colour: Self::read_colour(color_str) });
+        }
+        
+        Ok(DigPlan { plan })
+    }
+
+    /// Reads the direction, the distance and the six digits of the hexadecimal code of a line like `R 6 (#70c710)`.
+    fn read_line<'a>(line: &Line<'a>) -> Result<(Direction, u64, &'a str), ParseError> {
+        let plan_components: Vec<&str> = line.text.split_whitespace().collect();
+        match plan_components[..] {
+            [dir_str, steps_str, color_str] => {
+                let mut letters = dir_str.chars();
+                let direction = match (letters.next().and_then(Direction::from_letter), letters.next()) {
+                    (Some(direction), None) => direction,
+                    _ => return Err(line.error(dir_str, format!("expected `U`, `D`, `L` or `R`, found `{dir_str}`")))
+                };
+                let steps: u64 = line.parse(steps_str)?;
+                let hex_code = color_str.strip_prefix("(#").and_then(|code| code.strip_suffix(")"));
+                match hex_code {
+                    Some(hex_code) if hex_code.len() == 6 && hex_code.chars().all(|character| character.is_ascii_hexdigit()) => {
+                        return Ok((direction, steps, hex_code));
+                    },
+                    _ => return Err(line.error(color_str, format!("expected a colour like `(#70c710)`, found `{color_str}`")))
+                }
+            }
+            _ => return Err(line.error_at(1, format!("expected a direction, a distance and a colour, found `{}`", line.text)))
+        }
+    }
+
+    /// Reads the six digits of a hexadecimal code that [`DigPlan::read_line`] has already checked.
+    fn read_colour(color_str: &str) -> u32 {
+        return u32::from_str_radix(color_str, 16).unwrap();
+    }
+
+    /// Returns the corners of the trench in the order they are dug, starting from the origin.
+    pub fn get_vertices(&self) -> Vec<(i128, i128)> {
+        let mut vertices: Vec<(i128, i128)> = vec![(0, 0)];
+
+        let mut current_pos: (i128, i128) = (0, 0);
+
+        for dig_step in &self.plan {
+            let DigStep { direction, steps, .. } = dig_step;
+            let (dx, dy) = direction.delta();
+            current_pos.0 += dx as i128 * *steps as i128;
+            current_pos.1 += dy as i128 * *steps as i128;
+            vertices.push(current_pos);
+        }
+        return vertices;
+    }
+}
+
+/// The number of cubic metres of lava the lagoon holds, including its edge, from the vertices of [`DigPlan::get_vertices`].
+pub fn picks(vertices: &Vec<(i128, i128)>) -> i128 {
+    return lattice_points(vertices);
+}
+
+impl FromStr for DigPlan {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return DigPlan::parse(input);
+    }
+}
+
+/// Solver for day 18. Part one follows the directions and distances; part two follows the hexadecimal codes.
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+
+    type Input = (DigPlan, DigPlan);
+    type PartOne = i128;
+    type PartTwo = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Ok((DigPlan::parse(input)?, DigPlan::parse_corrected(input)?));
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return picks(&input.0.get_vertices());
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return picks(&input.1.get_vertices());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use aoc_gen::Size;
+    use proptest::prelude::*;
+
+    use crate::{DigPlan, picks};
+
+    const TEST_INPUT: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    pub fn get_area() {
+        let dig_plan = DigPlan::parse(TEST_INPUT).unwrap();
+        assert_eq!(62, picks(&dig_plan.get_vertices()));
+        assert_eq!(dig_plan.plan[0].colour, 0x70c710);
+        assert_eq!(dig_plan.plan[13].colour, 0x7a21e3);
+    }
+
+    #[test]
+    pub fn get_area_corrected() {
+        let dig_plan = DigPlan::parse_corrected(TEST_INPUT).unwrap();
+        assert_eq!(952408144115, picks(&dig_plan.get_vertices()));
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let error = DigPlan::parse("R 6 (#70c710)\nX 5 (#0dc571)\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = DigPlan::parse("R 6 (#70c710)\nD 5 (#0dc5)\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        let error = DigPlan::parse_corrected("R 6 (#70c714)\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 12));
+    }
+
+    /// Digs the trench one cube at a time, then fills in from outside a box around it. Whatever the fill cannot reach
+    /// is the lagoon.
+    fn brute_force_lagoon(input: &str) -> i128 {
+        let mut trench: HashSet<(i64, i64)> = HashSet::from([(0, 0)]);
+        let mut position = (0, 0);
+        for line in input.lines() {
+            let mut words = line.split_whitespace();
+            let (dx, dy) = match words.next().unwrap() {
+                "U" => (0, -1),
+                "D" => (0, 1),
+                "L" => (-1, 0),
+                _ => (1, 0)
+            };
+            for _ in 0..words.next().unwrap().parse::<u64>().unwrap() {
+                position = (position.0 + dx, position.1 + dy);
+                trench.insert(position);
+            }
+        }
+
+        let min_x = trench.iter().map(|(x, _)| x).min().unwrap() - 1;
+        let max_x = trench.iter().map(|(x, _)| x).max().unwrap() + 1;
+        let min_y = trench.iter().map(|(_, y)| y).min().unwrap() - 1;
+        let max_y = trench.iter().map(|(_, y)| y).max().unwrap() + 1;
+        let mut outside: HashSet<(i64, i64)> = HashSet::from([(min_x, min_y)]);
+        let mut to_fill = vec![(min_x, min_y)];
+        while let Some((x, y)) = to_fill.pop() {
+            for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                let in_box = (min_x..=max_x).contains(&next.0) && (min_y..=max_y).contains(&next.1);
+                if in_box && !trench.contains(&next) && outside.insert(next) {
+                    to_fill.push(next);
+                }
+            }
+        }
+        return ((max_x - min_x + 1) * (max_y - min_y + 1)) as i128 - outside.len() as i128;
+    }
+
+    proptest! {
+        #[test]
+        fn test_lagoon_matches_brute_force(seed in any::<u64>(), count in 1..6usize, width in 1..6usize, height in 1..6usize, turn in 0..4usize) {
+            let input = aoc_gen::day18::generate(&mut aoc_gen::rng(seed), &Size { count, width, height });
+            // Reflect or turn the trench, so that it is not always dug the same way round from its top left corner.
+            let turns = ["UDLR", "LRUD", "DURL", "RLDU"];
+            let input: String = input.lines()
+                .map(|line| {
+                    let direction = turns[0].find(&line[..1]).unwrap();
+                    return format!("{}{}\n", &turns[turn][direction..direction + 1], &line[1..]);
+                })
+                .collect();
+            let dig_plan = DigPlan::parse(&input).unwrap();
+            prop_assert_eq!(picks(&dig_plan.get_vertices()), brute_force_lagoon(&input));
+        }
+    }
+}
\ No newline at end of file
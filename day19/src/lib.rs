@@ -0,0 +1,401 @@
+//--------------------------------------------------------------------------------
+// Day 19: Aplenty
+// Rene Jotham Culaway
+//--------------------------------------------------------------------------------
+
+//! Sorts the machine parts through the Elves' workflows and counts the ratings that get accepted.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::Read;
+use std::str::FromStr;
+
+use aoc_common::{lines, Line, ParseError, Solver};
+use aoc_interval::IntervalBox;
+use tracing::{debug, trace};
+
+/// What a rule (or a whole workflow) does with a part.
+#[derive(Debug)]
+pub enum RuleResult {
+    NextWorkflow(String),
+    NotMatching,
+    Accept,
+    Reject
+}
+
+/// A machine part and its four ratings.
+#[derive(Debug, Clone)]
+pub struct Part {
+    /// How extremely cool the part looks.
+    pub x: u32,
+    /// How musical the part is.
+    pub m: u32,
+    /// How aerodynamic the part is.
+    pub a: u32,
+    /// How shiny the part is.
+    pub s: u32
+}
+
+/// The workflows and the parts to sort through them.
+#[derive(Debug)]
+pub struct Sorter {
+    /// The rules of each workflow, in order, keyed by the workflow's name.
+    pub workflows: HashMap<String, Vec<String>>,
+    /// The parts, in the order they are listed.
+    pub parts: Vec<Part>
+}
+
+impl Sorter {
+    /// Reads the workflows and parts from `file_name`.
+    pub fn load_from_file(file_name: &str) -> Result<Self, ParseError> {
+        return aoc_common::parse_file(file_name, Sorter::parse);
+    }
+
+    /// Reads the workflows and parts from `reader`.
+    pub fn from_reader(reader: impl Read) -> Result<Self, ParseError> {
+        return aoc_common::parse_reader(reader, Sorter::parse);
+    }
+
+    /// Reads the workflows and parts from the puzzle input, which are separated by a blank line.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut workflows: HashMap<String, Vec<String>> = HashMap::new();
+        let mut parts: Vec<Part> = vec![];
+        let mut destinations: Vec<(Line, &str)> = vec![];
+    
+        let mut lines = lines(input);
+        for line in lines.by_ref().take_while(|line| !line.text.is_empty()) {
+            let Some(rest) = line.text.strip_suffix("}") else {
+                return Err(line.end_error("expected `}` at the end of the workflow"));
+            };
+            let (workflow_name, rest_removed_curly) = line.split_once(rest, "{")?;
+            if workflow_name.is_empty() {
+                return Err(line.error_at(1, "expected a workflow name"));
+            }
+
+            let rules: Vec<&str> = rest_removed_curly.split(",").collect();
+            for (i, rule) in rules.iter().enumerate() {
+                let destination = Self::check_rule(&line, rule, i + 1 == rules.len())?;
+                destinations.push((line, destination));
+            }
+            if workflows.insert(workflow_name.to_string(), rules.iter().map(|rule| rule.to_string()).collect()).is_some() {
+                return Err(line.error_at(1, format!("found a second workflow named `{workflow_name}`")));
+            }
+        }
+        if !workflows.contains_key("in") {
+            return Err(ParseError::end_of_input(input, "expected a workflow named `in`"));
+        }
+        for (line, destination) in destinations {
+            if !matches!(destination, "A" | "R") && !workflows.contains_key(destination) {
+                return Err(line.error(destination, format!("there is no workflow `{destination}`")));
+            }
+        }
+        
+        for line in lines {
+            let Some(ratings_str) = line.text.strip_prefix("{").and_then(|text| text.strip_suffix("}")) else {
+                return Err(line.error_at(1, format!("expected a part like `{{x=1,m=2,a=3,s=4}}`, found `{}`", line.text)));
+            };
+            let mut ratings: [u32; 4] = [0; 4];
+            let rating_strs: Vec<&str> = ratings_str.split(",").collect();
+            if rating_strs.len() != 4 {
+                return Err(line.error_at(1, format!("expected 4 ratings, found {}", rating_strs.len())));
+            }
+            for ((rating_str, expected_name), rating) in rating_strs.iter().zip(["x", "m", "a", "s"]).zip(ratings.iter_mut()) {
+                let (name, value) = line.split_once(rating_str, "=")?;
+                if name != expected_name {
+                    return Err(line.error(rating_str, format!("expected the `{expected_name}` rating, found `{name}`")));
+                }
+                *rating = line.parse(value)?;
+            }
+            let [x, m, a, s] = ratings;
+            parts.push(Part { x, m, a, s });
+        }
+    
+        Ok(Sorter { workflows, parts })
+    }
+
+    /// Makes sure `rule` is either `rating<value:destination`, `rating>value:destination` or, if it is the `last` rule of its
+    /// workflow, just `destination`. Returns the destination.
+    fn check_rule<'a>(line: &Line<'a>, rule: &'a str, last: bool) -> Result<&'a str, ParseError> {
+        let Some((condition, destination)) = rule.split_once(":") else {
+            if !last {
+                return Err(line.error(rule, format!("expected a condition in `{rule}`")));
+            }
+            return Ok(rule);
+        };
+        if last {
+            return Err(line.error(rule, format!("expected the last rule to have no condition, found `{rule}`")));
+        }
+        if !matches!(condition.get(..1), Some("x" | "m" | "a" | "s")) || !matches!(condition.get(1..2), Some("<" | ">")) {
+            return Err(line.error(condition, format!("expected a condition like `a<2006`, found `{condition}`")));
+        }
+        let value: u32 = line.parse(&condition[2..])?;
+        if value == 0 && &condition[1..2] == "<" {
+            return Err(line.error(condition, format!("no rating can pass `{condition}`")));
+        }
+        Ok(destination)
+    }
+
+    fn process_rule(rule: &String, part: &Part) -> RuleResult {
+        match rule.split(":").collect::<Vec<&str>>()[..] {
+            [condition, result_if_true] => {
+
+                let (rating, rest) = condition.split_at(1);
+                let (relation, value_str) = rest.split_at(1);
+                let value: u32 = value_str.parse().unwrap();
+
+                let value_to_test = match rating {
+                    "x" => part.x,
+                    "m" => part.m,
+                    "a" => part.a,
+                    "s" => part.s,
+                    _ => unreachable!()
+                };
+
+                let is_match: bool = match relation {
+                    ">" => value_to_test > value,
+                    "<" => value_to_test < value,
+                    _ => unreachable!()
+                };
+
+                if !is_match {
+                    return RuleResult::NotMatching;
+                }
+
+                match result_if_true {
+                    "A" => { RuleResult::Accept }
+                    "R" => { RuleResult::Reject }
+                    _ => { RuleResult::NextWorkflow(result_if_true.to_string()) }
+                }
+            },
+            [unconditonal_result] => {
+                match unconditonal_result {
+                    "A" => { RuleResult::Accept }
+                    "R" => { RuleResult::Reject }
+                    _ => { RuleResult::NextWorkflow(unconditonal_result.to_string()) }
+                }
+            }
+            _ => unreachable!()
+        }
+    }
+
+    /// Applies the rules of the workflow named `workflow_name` to `part` until one of them matches.
+    pub fn process_workflow(&self, workflow_name: &String, part: &Part) -> RuleResult {
+        let workflow = self.workflows.get(workflow_name).unwrap();
+        let mut result = RuleResult::NotMatching;
+        for rule in workflow {
+            match Self::process_rule(rule, part) {
+                RuleResult::NotMatching => {},
+                next_result => {
+                    result = next_result;
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Sends every part through the workflows from `in`, and adds up the ratings of the accepted ones.
+    /// A part that comes back to a workflow it has already been through goes round that loop forever, so it is never accepted.
+    pub fn process_parts(&self) -> u64 {
+        let mut accepted: Vec<Part> = vec![];
+        for part in &self.parts {
+            let mut current_result: RuleResult = RuleResult::NextWorkflow("in".to_string());
+            let mut visited: HashSet<String> = HashSet::new();
+
+            trace!(?part, "sorting a part");
+            loop {
+                trace!(?current_result);
+                match current_result {
+                    RuleResult::Accept => {
+                        accepted.push(part.clone());
+                        break;
+                    },
+                    RuleResult::NextWorkflow(next) => {
+                        if !visited.insert(next.clone()) {
+                            debug!(?part, workflow = next, "went round a loop");
+                            break;
+                        }
+                        current_result = self.process_workflow(&next, part);
+                    },
+                    RuleResult::Reject| RuleResult::NotMatching => {
+                        break;
+                    },
+                }
+            }
+            
+        }
+
+        debug!(?accepted, "sorted the parts");
+        // The ratings can be as large as a `u32` goes, so four of them, let alone every accepted part's, may not fit in one.
+        accepted.iter().map(|part| part.x as u64 + part.m as u64 + part.a as u64 + part.s as u64).sum()
+    }
+
+    // In part 2, the problem becomes similar to the seed mapping day.
+    /// Counts the combinations of ratings from `min` to `max` (inclusive) that the workflows accept.
+    /// Each condition cuts the box of ratings still to be sorted in two, across the axis of the rating it tests. Each box
+    /// keeps the workflows it has been through, and one that comes back to any of them goes round that loop forever.
+    pub fn count_accepted_permutations(&self, min: u32, max: u32) -> u64 {
+        let ratings = (min as i64)..(max as i64 + 1);
+        let mut accepted: Vec<IntervalBox<4>> = vec![];
+
+        let mut to_process: VecDeque<(IntervalBox<4>, String, HashSet<String>)> = VecDeque::from(
+            [
+                (IntervalBox::new([ratings.clone(), ratings.clone(), ratings.clone(), ratings]), "in".to_string(), HashSet::new()),
+            ]
+        );
+        while let Some((current, workflow_name, mut visited)) = to_process.pop_front() {
+            if !visited.insert(workflow_name.clone()) {
+                debug!(?current, workflow = workflow_name, "went round a loop");
+                continue;
+            }
+            let workflow = self.workflows.get(&workflow_name).unwrap();
+            let mut leftover: Option<IntervalBox<4>> = Some(current);
+
+            for rule in workflow {
+                let Some(current) = leftover.take() else {
+                    break;
+                };
+                let (passing, destination) = match rule.split_once(":") {
+                    Some((condition, result_if_true)) => {
+                        let (rating, rest) = condition.split_at(1);
+                        let (relation, value_str) = rest.split_at(1);
+                        let value: i64 = value_str.parse().unwrap();
+                        let axis: usize = match rating {
+                            "x" => 0,
+                            "m" => 1,
+                            "a" => 2,
+                            "s" => 3,
+                            _ => unreachable!()
+                        };
+
+                        let (passing, nonpassing) = match relation {
+                            ">" => {
+                                let (below, above) = current.split_at(axis, value + 1);
+                                (above, below)
+                            },
+                            "<" => current.split_at(axis, value),
+                            _ => unreachable!()
+                        };
+                        leftover = nonpassing;
+                        trace!(workflow = workflow_name, rule, ?passing, ?leftover, "split the ratings");
+                        (passing, result_if_true)
+                    },
+                    None => (Some(current), rule.as_str())
+                };
+
+                let Some(passing) = passing else {
+                    continue;
+                };
+                match destination {
+                    "A" => { accepted.push(passing) },
+                    "R" => {},
+                    next_workflow => { to_process.push_back((passing, String::from(next_workflow), visited.clone())) }
+                }
+            }
+        }
+        debug!(?accepted, "sorted every combination of ratings");
+        accepted.iter().map(|accepted_box| accepted_box.volume()).sum()
+    }
+}
+
+impl FromStr for Sorter {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        return Sorter::parse(input);
+    }
+}
+
+/// Solver for day 19.
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+
+    type Input = Sorter;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return Sorter::parse(input);
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        return input.process_parts();
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        return input.count_accepted_permutations(1, 4000);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Sorter;
+
+    const TEST_INPUT: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2005,s=20}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn test_process_parts() {
+        let sorter: Sorter = Sorter::parse(TEST_INPUT).unwrap();
+        assert_eq!(sorter.process_parts(), 19114);
+    }
+
+    #[test]
+    fn test_count_accepted_permutations() {
+        let sorter: Sorter = Sorter::parse(TEST_INPUT).unwrap();
+        assert_eq!(sorter.count_accepted_permutations(1, 4000), 167409079868000);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Sorter::parse("in{x>10:one,R}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+        let error = Sorter::parse("in{x>10:A,R}\n\n{x=1,m=2,a=3,s=4}\n{x=1,m=2,s=3,a=4}\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 10));
+        let error = Sorter::parse("in{q>10:A,R}\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert!(Sorter::parse("in{x<10:b,c}\nb{c}\nc{A}\n").is_ok());
+    }
+
+    #[test]
+    fn test_loops() {
+        // The workflows point back at `in`, but no part that gets to `a` can pass `x>20`.
+        let sorter: Sorter = Sorter::parse("in{x<10:a,A}\na{x>20:in,R}\n\n{x=5,m=1,a=1,s=1}\n{x=30,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(sorter.process_parts(), 33);
+        assert_eq!(sorter.count_accepted_permutations(1, 4000), 3991 * 4000 * 4000 * 4000);
+
+        // Here the parts with `x` below 5 do go round between `in` and `a` forever, so they are never accepted.
+        let sorter: Sorter = Sorter::parse("in{x<10:a,A}\na{x<5:in,A}\n\n{x=1,m=1,a=1,s=1}\n{x=7,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(sorter.process_parts(), 10);
+        assert_eq!(sorter.count_accepted_permutations(1, 4000), 3996 * 4000 * 4000 * 4000);
+        let sorter: Sorter = Sorter::parse("in{b}\nb{c}\nc{x<5:b,A}\n\n{x=4,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(sorter.process_parts(), 0);
+        assert_eq!(sorter.count_accepted_permutations(1, 4000), 3996 * 4000 * 4000 * 4000);
+    }
+
+    #[test]
+    fn test_large_ratings() {
+        let sorter: Sorter = Sorter::parse("in{A}\n\n{x=4294967295,m=4294967295,a=4294967295,s=4294967295}\n{x=4294967295,m=1,a=1,s=1}\n").unwrap();
+        assert_eq!(sorter.process_parts(), 5 * u32::MAX as u64 + 3);
+    }
+}
\ No newline at end of file